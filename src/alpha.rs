@@ -0,0 +1,152 @@
+use super::{
+    constrained::edge_key, edge::Edge, face::Face, point::Point, tetrahedron::Tetrahedron,
+};
+use std::collections::HashMap;
+
+// alpha values are squared radii, like `Tetrahedron::circumradius`
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlphaComplex {
+    pub tetrahedrons: Vec<(Tetrahedron, f64)>,
+    pub faces: Vec<(Face, f64)>,
+    pub edges: Vec<(Edge, f64)>,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlphaShape {
+    pub tetrahedrons: Vec<Tetrahedron>,
+    pub boundary: Vec<Face>,
+}
+
+fn vertices(tetra: &Tetrahedron) -> [Point; 4] {
+    [tetra.p1, tetra.p2, tetra.p3, tetra.p4]
+}
+
+impl AlphaComplex {
+    pub fn new(tetrahedrons: &[Tetrahedron]) -> Self {
+        let mut faces = HashMap::<Face, Vec<usize>>::new();
+        let mut edges = HashMap::new();
+        for (i, tetra) in tetrahedrons.iter().enumerate() {
+            for &face in tetra.faces().iter() {
+                faces.entry(face).or_default().push(i);
+            }
+            for &(a, b) in tetra.edges().iter() {
+                edges
+                    .entry(edge_key(&a, &b))
+                    .or_insert_with(|| (Edge::new(a, b), Vec::new()))
+                    .1
+                    .push(i);
+            }
+        }
+
+        // a simplex whose smallest circumsphere holds a neighbouring vertex is attached, and only
+        // enters the complex together with its first coface
+        let attached = |incident: &[usize], center: &Point, radius: f64, own: &[Point]| {
+            incident.iter().any(|&i| {
+                vertices(&tetrahedrons[i])
+                    .iter()
+                    .any(|point| !own.contains(point) && point.dist(center) < radius)
+            })
+        };
+
+        let face_alphas = faces
+            .iter()
+            .map(|(&face, incident)| {
+                let cofaces = incident
+                    .iter()
+                    .map(|&i| tetrahedrons[i].circumradius)
+                    .fold(f64::INFINITY, f64::min);
+                let center = face.circumcenter();
+                let radius = center.dist(&face.p1);
+                let alpha = if attached(incident, &center, radius, &[face.p1, face.p2, face.p3]) {
+                    cofaces
+                } else {
+                    radius.min(cofaces)
+                };
+                (face, alpha)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let edges = edges
+            .into_values()
+            .map(|(edge, incident)| {
+                let cofaces = incident
+                    .iter()
+                    .flat_map(|&i| tetrahedrons[i].faces())
+                    .filter(|face| {
+                        [face.p1, face.p2, face.p3].contains(&edge.p1)
+                            && [face.p1, face.p2, face.p3].contains(&edge.p2)
+                    })
+                    .map(|face| face_alphas[&face])
+                    .fold(f64::INFINITY, f64::min);
+                let center = (edge.p1 + edge.p2) / 2.0;
+                let radius = center.dist(&edge.p1);
+                let alpha = if attached(&incident, &center, radius, &[edge.p1, edge.p2]) {
+                    cofaces
+                } else {
+                    radius.min(cofaces)
+                };
+                (edge, alpha)
+            })
+            .collect();
+
+        Self {
+            tetrahedrons: tetrahedrons
+                .iter()
+                .map(|&tetra| (tetra, tetra.circumradius))
+                .collect(),
+            faces: face_alphas.into_iter().collect(),
+            edges,
+        }
+    }
+
+    pub fn shape(&self, alpha: f64) -> AlphaShape {
+        let tetrahedrons = self
+            .tetrahedrons
+            .iter()
+            .filter(|(_, value)| *value <= alpha)
+            .map(|&(tetra, _)| tetra)
+            .collect::<Vec<_>>();
+
+        let mut interior = HashMap::<Face, Vec<Point>>::new();
+        for tetra in tetrahedrons.iter() {
+            let opposite = [tetra.p4, tetra.p3, tetra.p2, tetra.p1];
+            for (&face, &opposite) in tetra.faces().iter().zip(opposite.iter()) {
+                interior.entry(face).or_default().push(opposite);
+            }
+        }
+
+        // faces bounding one interior tetrahedron are turned to face away from it, dangling faces
+        // of the complex are kept as they are
+        let boundary = self
+            .faces
+            .iter()
+            .filter(|(_, value)| *value <= alpha)
+            .filter_map(|&(face, _)| match interior.get(&face).map(Vec::as_slice) {
+                None => Some(face),
+                Some([opposite]) if face.normal().dot(&(*opposite - face.p1)) > 0.0 => {
+                    Some(Face::new(face.p1, face.p3, face.p2))
+                }
+                Some([_]) => Some(face),
+                Some(_) => None,
+            })
+            .collect();
+
+        AlphaShape {
+            tetrahedrons,
+            boundary,
+        }
+    }
+
+    pub fn covering_alpha(&self) -> f64 {
+        let mut alphas = HashMap::<Point, f64>::new();
+        for &(tetra, value) in self.tetrahedrons.iter() {
+            for point in vertices(&tetra) {
+                let alpha = alphas.entry(point).or_insert(f64::INFINITY);
+                *alpha = alpha.min(value);
+            }
+        }
+        alphas.into_values().fold(0.0, f64::max)
+    }
+}
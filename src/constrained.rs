@@ -0,0 +1,274 @@
+use super::{
+    face::Face, insert_delaunay, point::Point, super_tetrahedron, tetrahedron::Tetrahedron,
+    without_super_tetrahedron,
+};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    error::Error,
+    fmt,
+};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConstrainedDelaunay {
+    pub tetrahedrons: Vec<Tetrahedron>,
+    pub subfaces: Vec<Face>,
+    pub steiner_points: Vec<Point>,
+}
+
+// the flood fill in `interior` is only exact once every subface is recovered, so running out of
+// steiner points is an error rather than a partial result
+#[derive(Debug)]
+pub enum ConstrainedError {
+    SteinerPointLimit {
+        max_steiner_points: usize,
+        missing_subfaces: usize,
+    },
+}
+
+impl fmt::Display for ConstrainedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConstrainedError::SteinerPointLimit {
+                max_steiner_points,
+                missing_subfaces,
+            } => write!(
+                f,
+                "{} surface triangles could not be recovered with {} steiner points",
+                missing_subfaces, max_steiner_points
+            ),
+        }
+    }
+}
+
+impl Error for ConstrainedError {}
+
+pub(crate) type PointKey = (u64, u64, u64);
+
+fn key(point: &Point) -> PointKey {
+    (point.x.to_bits(), point.y.to_bits(), point.z.to_bits())
+}
+
+pub(crate) fn edge_key(a: &Point, b: &Point) -> (PointKey, PointKey) {
+    let (a, b) = (key(a), key(b));
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn midpoint(a: &Point, b: &Point) -> Point {
+    Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0, (a.z + b.z) / 2.0)
+}
+
+pub(crate) fn longest_edge(Face { p1, p2, p3 }: &Face) -> (Point, Point) {
+    *[(*p1, *p2), (*p2, *p3), (*p3, *p1)]
+        .iter()
+        .max_by(|(a1, b1), (a2, b2)| a1.dist(b1).partial_cmp(&a2.dist(b2)).unwrap())
+        .unwrap()
+}
+
+pub(crate) fn split_subfaces(
+    subfaces: &[Face],
+    splits: &HashMap<(PointKey, PointKey), Point>,
+) -> Vec<Face> {
+    let mut queue = subfaces.iter().copied().collect::<VecDeque<_>>();
+    let mut result = Vec::with_capacity(subfaces.len());
+
+    while let Some(face) = queue.pop_front() {
+        let Face { p1, p2, p3 } = face;
+        let split = [(p1, p2, p3), (p2, p3, p1), (p3, p1, p2)]
+            .iter()
+            .find_map(|&(a, b, c)| splits.get(&edge_key(&a, &b)).map(|&m| (a, b, c, m)));
+
+        match split {
+            Some((a, b, c, m)) => {
+                queue.push_back(Face::new(a, m, c));
+                queue.push_back(Face::new(m, b, c));
+            }
+            None => result.push(face),
+        }
+    }
+
+    result
+}
+
+pub(crate) fn coplanar(a: &Face, b: &Face) -> bool {
+    let (n, m) = (a.normal(), b.normal());
+    n.dot(&m) > 0.0 && n.cross(&m).norm() <= 1e-12 * n.norm() * m.norm()
+}
+
+// a subface whose circumcircle holds a coplanar vertex can never be a face of the tetrahedralization,
+// so subfaces sharing a plane are flipped back to a Delaunay triangulation of it after splitting
+fn flip_coplanar(mut subfaces: Vec<Face>) -> Vec<Face> {
+    loop {
+        let mut edges = HashMap::<_, Vec<usize>>::new();
+        for (i, Face { p1, p2, p3 }) in subfaces.iter().enumerate() {
+            for (a, b) in [(p1, p2), (p2, p3), (p3, p1)].iter() {
+                edges.entry(edge_key(a, b)).or_default().push(i);
+            }
+        }
+
+        let mut flipped = HashSet::new();
+        for incident in edges.values() {
+            let (i, j) = match incident[..] {
+                [i, j] if !flipped.contains(&i) && !flipped.contains(&j) => (i, j),
+                _ => continue,
+            };
+            let (first, second) = (subfaces[i], subfaces[j]);
+            if !coplanar(&first, &second) {
+                continue;
+            }
+            let others = [second.p1, second.p2, second.p3];
+            let Face { p1, p2, p3 } = first;
+            let (a, b, c) = *[(p1, p2, p3), (p2, p3, p1), (p3, p1, p2)]
+                .iter()
+                .find(|(a, b, _)| others.contains(a) && others.contains(b))
+                .unwrap();
+            let d = *others
+                .iter()
+                .find(|&&point| point != a && point != b)
+                .unwrap();
+
+            // only the diagonal of a convex quadrilateral can be flipped
+            let normal = first.normal();
+            let side = |point: &Point| (d - c).cross(&(*point - c)).dot(&normal);
+            let center = first.circumcenter();
+            if side(&a) * side(&b) < 0.0 && d.dist(&center) < center.dist(&a) * (1.0 - 1e-9) {
+                subfaces[i] = Face::new(a, d, c);
+                subfaces[j] = Face::new(d, b, c);
+                flipped.insert(i);
+                flipped.insert(j);
+            }
+        }
+        if flipped.is_empty() {
+            return subfaces;
+        }
+    }
+}
+
+pub fn constrained_delaunay(
+    points: &[Point],
+    shape: &[Face],
+    max_steiner_points: usize,
+) -> Result<ConstrainedDelaunay, ConstrainedError> {
+    let mut seen = HashSet::new();
+    let points = points
+        .iter()
+        .copied()
+        .chain(
+            shape
+                .iter()
+                .flat_map(|face| vec![face.p1, face.p2, face.p3]),
+        )
+        .filter(|point| seen.insert(key(point)))
+        .collect::<Vec<_>>();
+    let mut subfaces = flip_coplanar(shape.to_vec());
+    let mut steiner_points = Vec::new();
+
+    // steiner points split subface edges, so they stay inside the super tetrahedron and can be
+    // inserted into the tetrahedralization as they come
+    let super_tetra = super_tetrahedron(&points);
+    let mut triangulation = vec![super_tetra];
+    for point in points.iter() {
+        insert_delaunay(&mut triangulation, point);
+    }
+
+    loop {
+        let tetrahedrons = without_super_tetrahedron(&triangulation, &super_tetra);
+        let faces = tetrahedrons
+            .iter()
+            .flat_map(|tetra| tetra.faces().to_vec())
+            .collect::<HashSet<_>>();
+
+        let missing = subfaces
+            .iter()
+            .filter(|face| !faces.contains(face))
+            .collect::<Vec<_>>();
+        let mut splits = HashMap::new();
+        for face in missing.iter() {
+            let (a, b) = longest_edge(face);
+            splits
+                .entry(edge_key(&a, &b))
+                .or_insert_with(|| midpoint(&a, &b));
+        }
+
+        if splits.is_empty() {
+            return Ok(ConstrainedDelaunay {
+                tetrahedrons,
+                subfaces,
+                steiner_points,
+            });
+        }
+        if steiner_points.len() + splits.len() > max_steiner_points {
+            return Err(ConstrainedError::SteinerPointLimit {
+                max_steiner_points,
+                missing_subfaces: missing.len(),
+            });
+        }
+
+        for &point in splits.values() {
+            insert_delaunay(&mut triangulation, &point);
+            steiner_points.push(point);
+        }
+        subfaces = flip_coplanar(split_subfaces(&subfaces, &splits));
+    }
+}
+
+impl ConstrainedDelaunay {
+    pub fn missing_subfaces(&self) -> Vec<Face> {
+        let faces = self
+            .tetrahedrons
+            .iter()
+            .flat_map(|tetra| tetra.faces().to_vec())
+            .collect::<HashSet<_>>();
+        self.subfaces
+            .iter()
+            .filter(|face| !faces.contains(face))
+            .copied()
+            .collect()
+    }
+
+    pub fn interior(&self) -> Vec<Tetrahedron> {
+        let constraints = self.subfaces.iter().copied().collect::<HashSet<_>>();
+
+        let mut face_tetrahedrons = HashMap::<_, Vec<usize>>::new();
+        for (i, tetra) in self.tetrahedrons.iter().enumerate() {
+            for &face in tetra.faces().iter() {
+                face_tetrahedrons.entry(face).or_default().push(i);
+            }
+        }
+
+        let mut inside = vec![None; self.tetrahedrons.len()];
+        let mut queue = VecDeque::new();
+        for (face, tetrahedrons) in face_tetrahedrons.iter() {
+            if let [tetra] = tetrahedrons[..] {
+                if inside[tetra].is_none() {
+                    let parity = constraints.contains(face);
+                    inside[tetra] = Some(parity);
+                    queue.push_back(tetra);
+                }
+            }
+        }
+
+        while let Some(current) = queue.pop_front() {
+            let parity = inside[current].unwrap();
+            for face in self.tetrahedrons[current].faces().iter() {
+                for &neighbour in face_tetrahedrons[face].iter() {
+                    if inside[neighbour].is_none() {
+                        inside[neighbour] = Some(parity ^ constraints.contains(face));
+                        queue.push_back(neighbour);
+                    }
+                }
+            }
+        }
+
+        self.tetrahedrons
+            .iter()
+            .zip(inside)
+            .filter(|(_, inside)| inside.unwrap_or(false))
+            .map(|(&tetra, _)| tetra)
+            .collect()
+    }
+}
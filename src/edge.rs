@@ -1,12 +1,14 @@
-use super::point::Point;
+use super::{point::Point, scalar::Scalar};
 
-pub struct Edge {
-    pub p1: Point,
-    pub p2: Point,
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Edge<T = f64> {
+    pub p1: Point<T>,
+    pub p2: Point<T>,
 }
 
-impl Edge {
-    pub fn new(p1: Point, p2: Point) -> Self {
+impl<T: Scalar> Edge<T> {
+    pub fn new(p1: Point<T>, p2: Point<T>) -> Self {
         Self { p1, p2 }
     }
 }
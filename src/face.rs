@@ -1,20 +1,54 @@
-use super::point::Point;
+use super::{point::Point, scalar::Scalar};
 use std::hash::{Hash, Hasher};
 
-#[derive(Copy, Clone)]
-pub struct Face {
-    pub p1: Point,
-    pub p2: Point,
-    pub p3: Point,
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Face<T = f64> {
+    pub p1: Point<T>,
+    pub p2: Point<T>,
+    pub p3: Point<T>,
 }
 
-impl Face {
-    pub fn new(p1: Point, p2: Point, p3: Point) -> Self {
+impl<T: Scalar> Face<T> {
+    pub fn new(p1: Point<T>, p2: Point<T>, p3: Point<T>) -> Self {
         Self { p1, p2, p3 }
     }
+
+    pub fn normal(&self) -> Point<T> {
+        let (ux, uy, uz) = (
+            self.p2.x - self.p1.x,
+            self.p2.y - self.p1.y,
+            self.p2.z - self.p1.z,
+        );
+        let (vx, vy, vz) = (
+            self.p3.x - self.p1.x,
+            self.p3.y - self.p1.y,
+            self.p3.z - self.p1.z,
+        );
+        Point::new(uy * vz - uz * vy, uz * vx - ux * vz, ux * vy - uy * vx)
+    }
+
+    pub fn area(&self) -> T {
+        self.normal().norm().sqrt() / T::from_f64(2.0)
+    }
+
+    pub fn circumcenter(&self) -> Point<T> {
+        let (u, v) = (self.p2 - self.p1, self.p3 - self.p1);
+        let w = u.cross(&v);
+        self.p1 + (v * u.norm() - u * v.norm()).cross(&w) / (T::from_f64(2.0) * w.norm())
+    }
+
+    pub fn centroid(&self) -> Point<T> {
+        let three = T::from_f64(3.0);
+        Point::new(
+            (self.p1.x + self.p2.x + self.p3.x) / three,
+            (self.p1.y + self.p2.y + self.p3.y) / three,
+            (self.p1.z + self.p2.z + self.p3.z) / three,
+        )
+    }
 }
 
-impl PartialEq for Face {
+impl<T: Scalar> PartialEq for Face<T> {
     fn eq(&self, &Self { p1, p2, p3 }: &Self) -> bool {
         (self.p1 == p1 && self.p2 == p2 && self.p3 == p3)
             || (self.p1 == p1 && self.p2 == p3 && self.p3 == p2)
@@ -25,25 +59,25 @@ impl PartialEq for Face {
     }
 }
 
-impl Eq for Face {}
+impl<T: Scalar> Eq for Face<T> {}
 
-impl Hash for Face {
+impl<T: Scalar> Hash for Face<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         let mut points = [
             (
-                self.p1.x.to_bits(),
-                self.p1.y.to_bits(),
-                self.p1.z.to_bits(),
+                self.p1.x.to_f64().to_bits(),
+                self.p1.y.to_f64().to_bits(),
+                self.p1.z.to_f64().to_bits(),
             ),
             (
-                self.p2.x.to_bits(),
-                self.p2.y.to_bits(),
-                self.p2.z.to_bits(),
+                self.p2.x.to_f64().to_bits(),
+                self.p2.y.to_f64().to_bits(),
+                self.p2.z.to_f64().to_bits(),
             ),
             (
-                self.p3.x.to_bits(),
-                self.p3.y.to_bits(),
-                self.p3.z.to_bits(),
+                self.p3.x.to_f64().to_bits(),
+                self.p3.y.to_f64().to_bits(),
+                self.p3.z.to_f64().to_bits(),
             ),
         ];
         points.sort_unstable();
@@ -0,0 +1,183 @@
+use super::{face::Face, point::Point};
+use std::collections::HashSet;
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConvexHull {
+    pub faces: Vec<Face>,
+    pub vertices: Vec<Point>,
+}
+
+impl ConvexHull {
+    pub fn area(&self) -> f64 {
+        self.faces.iter().map(Face::area).sum()
+    }
+
+    pub fn volume(&self) -> f64 {
+        self.faces
+            .iter()
+            .map(|face| face.p1.dot(&face.p2.cross(&face.p3)))
+            .sum::<f64>()
+            / 6.0
+    }
+}
+
+struct Facet {
+    face: Face,
+    normal: Point,
+    outside: Vec<Point>,
+}
+
+impl Facet {
+    fn new(face: Face) -> Self {
+        let normal = face.normal();
+        Self {
+            face,
+            normal: normal / normal.length(),
+            outside: Vec::new(),
+        }
+    }
+
+    fn distance(&self, point: &Point) -> f64 {
+        self.normal.dot(&(*point - self.face.p1))
+    }
+}
+
+// the four points spanning the largest starting tetrahedron, or none if all points are coplanar
+fn initial_tetrahedron(points: &[Point], tolerance: f64) -> Option<[Point; 4]> {
+    let extreme = |key: fn(&Point) -> f64| {
+        let min = points
+            .iter()
+            .copied()
+            .min_by(|a, b| key(a).total_cmp(&key(b)))
+            .unwrap();
+        let max = points
+            .iter()
+            .copied()
+            .max_by(|a, b| key(a).total_cmp(&key(b)))
+            .unwrap();
+        (min, max)
+    };
+    let (a, b) = [extreme(|p| p.x), extreme(|p| p.y), extreme(|p| p.z)]
+        .iter()
+        .copied()
+        .max_by(|(a1, b1), (a2, b2)| a1.dist(b1).total_cmp(&a2.dist(b2)))
+        .unwrap();
+    let farthest = |distance: &dyn Fn(&Point) -> f64| {
+        points
+            .iter()
+            .copied()
+            .max_by(|p, q| distance(p).total_cmp(&distance(q)))
+            .unwrap()
+    };
+    let line = |p: &Point| (*p - a).cross(&(b - a)).length() / (b - a).length();
+    let c = farthest(&line);
+    if a.distance(&b) <= tolerance || line(&c) <= tolerance {
+        return None;
+    }
+    let normal = (b - a).cross(&(c - a));
+    let normal = normal / normal.length();
+    let d = farthest(&|p: &Point| normal.dot(&(*p - a)).abs());
+    if normal.dot(&(d - a)).abs() <= tolerance {
+        return None;
+    }
+    Some([a, b, c, d])
+}
+
+// quickhull: every facet keeps the points outside it, and the farthest of them replaces the facets
+// it sees with a fan from their horizon, until no point is outside any facet
+pub fn convex_hull(points: &[Point]) -> ConvexHull {
+    if points.len() < 4 {
+        return ConvexHull::default();
+    }
+    let (min, max) = points.iter().fold((points[0], points[0]), |(min, max), p| {
+        (
+            Point::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z)),
+            Point::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z)),
+        )
+    });
+    let tolerance = 1e-10 * min.distance(&max);
+    let [a, b, c, d] = match initial_tetrahedron(points, tolerance) {
+        Some(corners) => corners,
+        None => return ConvexHull::default(),
+    };
+
+    let mut facets = [(a, b, c), (a, b, d), (a, c, d), (b, c, d)]
+        .iter()
+        .zip([d, c, b, a].iter())
+        .map(|(&(p, q, r), opposite)| {
+            let facet = Facet::new(Face::new(p, q, r));
+            if facet.distance(opposite) > 0.0 {
+                Facet::new(Face::new(p, r, q))
+            } else {
+                facet
+            }
+        })
+        .collect::<Vec<_>>();
+    let assign = |point: Point, facets: &mut [Facet]| {
+        if let Some(facet) = facets
+            .iter_mut()
+            .find(|facet| facet.distance(&point) > tolerance)
+        {
+            facet.outside.push(point);
+        }
+    };
+    for &point in points.iter() {
+        assign(point, &mut facets);
+    }
+
+    while let Some(index) = facets.iter().position(|facet| !facet.outside.is_empty()) {
+        let apex = facets[index]
+            .outside
+            .iter()
+            .copied()
+            .max_by(|p, q| {
+                facets[index]
+                    .distance(p)
+                    .total_cmp(&facets[index].distance(q))
+            })
+            .unwrap();
+
+        let (visible, kept): (Vec<_>, Vec<_>) = facets
+            .into_iter()
+            .partition(|facet| facet.distance(&apex) > tolerance);
+        let edges = visible
+            .iter()
+            .flat_map(|facet| {
+                let Face { p1, p2, p3 } = facet.face;
+                [(p1, p2), (p2, p3), (p3, p1)]
+            })
+            .collect::<Vec<_>>();
+        let directed = edges.iter().copied().collect::<HashSet<_>>();
+
+        facets = kept;
+        let first = facets.len();
+        for &(p, q) in edges.iter() {
+            if !directed.contains(&(q, p)) {
+                facets.push(Facet::new(Face::new(p, q, apex)));
+            }
+        }
+        for point in visible.into_iter().flat_map(|facet| facet.outside) {
+            if point != apex {
+                assign(point, &mut facets[first..]);
+            }
+        }
+    }
+
+    let faces = facets
+        .into_iter()
+        .map(|facet| facet.face)
+        .collect::<Vec<_>>();
+    let on_hull = faces
+        .iter()
+        .flat_map(|face| [face.p1, face.p2, face.p3])
+        .collect::<HashSet<_>>();
+    let mut seen = HashSet::new();
+    let vertices = points
+        .iter()
+        .copied()
+        .filter(|point| on_hull.contains(point) && seen.insert(*point))
+        .collect();
+
+    ConvexHull { faces, vertices }
+}
@@ -0,0 +1,414 @@
+use crate::{
+    face::Face,
+    point::Point,
+    repair::face_vertices,
+    skeleton::{Armature, Skeleton},
+    skinning::Skin,
+};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const UNSIGNED_SHORT: u32 = 5123;
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+const LINES: u32 = 1;
+const TRIANGLES: u32 = 4;
+
+#[derive(Copy, Clone, Default)]
+pub struct Document<'a> {
+    pub faces: &'a [Face],
+    pub skeleton: Option<&'a Skeleton>,
+    pub armature: Option<&'a Armature>,
+    pub skin: Option<&'a Skin>,
+}
+
+#[derive(Default)]
+struct Builder {
+    buffer: Vec<u8>,
+    buffer_views: Vec<String>,
+    accessors: Vec<String>,
+    meshes: Vec<String>,
+    nodes: Vec<String>,
+    skins: Vec<String>,
+    scene: Vec<usize>,
+}
+
+impl Builder {
+    fn view(&mut self, bytes: &[u8], target: Option<u32>) -> usize {
+        while !self.buffer.len().is_multiple_of(4) {
+            self.buffer.push(0);
+        }
+        let target = target.map_or(String::new(), |target| format!(", \"target\": {}", target));
+        self.buffer_views.push(format!(
+            "{{\"buffer\": 0, \"byteOffset\": {}, \"byteLength\": {}{}}}",
+            self.buffer.len(),
+            bytes.len(),
+            target
+        ));
+        self.buffer.extend_from_slice(bytes);
+        self.buffer_views.len() - 1
+    }
+
+    fn positions(&mut self, points: &[Point]) -> usize {
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+        let mut bytes = Vec::with_capacity(points.len() * 12);
+        for point in points {
+            for (axis, &value) in [point.x, point.y, point.z].iter().enumerate() {
+                let value = value as f32;
+                min[axis] = min[axis].min(value);
+                max[axis] = max[axis].max(value);
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        let view = self.view(&bytes, Some(ARRAY_BUFFER));
+        self.accessors.push(format!(
+            "{{\"bufferView\": {}, \"componentType\": {}, \"count\": {}, \"type\": \"VEC3\", \"min\": [{}, {}, {}], \"max\": [{}, {}, {}]}}",
+            view, FLOAT, points.len(), min[0], min[1], min[2], max[0], max[1], max[2]
+        ));
+        self.accessors.len() - 1
+    }
+
+    fn indices(&mut self, indices: &[u32]) -> usize {
+        let bytes = indices
+            .iter()
+            .flat_map(|index| index.to_le_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let view = self.view(&bytes, Some(ELEMENT_ARRAY_BUFFER));
+        self.accessors.push(format!(
+            "{{\"bufferView\": {}, \"componentType\": {}, \"count\": {}, \"type\": \"SCALAR\"}}",
+            view,
+            UNSIGNED_INT,
+            indices.len()
+        ));
+        self.accessors.len() - 1
+    }
+
+    fn matrices(&mut self, matrices: &[[f32; 16]]) -> usize {
+        let bytes = matrices
+            .iter()
+            .flatten()
+            .flat_map(|value| value.to_le_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let view = self.view(&bytes, None);
+        self.accessors.push(format!(
+            "{{\"bufferView\": {}, \"componentType\": {}, \"count\": {}, \"type\": \"MAT4\"}}",
+            view,
+            FLOAT,
+            matrices.len()
+        ));
+        self.accessors.len() - 1
+    }
+
+    fn vec4(&mut self, bytes: &[u8], component_type: u32, count: usize) -> usize {
+        let view = self.view(bytes, Some(ARRAY_BUFFER));
+        self.accessors.push(format!(
+            "{{\"bufferView\": {}, \"componentType\": {}, \"count\": {}, \"type\": \"VEC4\"}}",
+            view, component_type, count
+        ));
+        self.accessors.len() - 1
+    }
+
+    fn mesh(&mut self, name: &str, attributes: &str, indices: usize, mode: u32) -> usize {
+        self.meshes.push(format!(
+            "{{\"name\": \"{}\", \"primitives\": [{{\"attributes\": {{{}}}, \"indices\": {}, \"mode\": {}}}]}}",
+            name, attributes, indices, mode
+        ));
+        self.meshes.len() - 1
+    }
+
+    fn node(&mut self, node: String) -> usize {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    fn json(&self, buffer_uri: Option<String>) -> String {
+        let uri = buffer_uri.map_or(String::new(), |uri| format!(", \"uri\": \"{}\"", uri));
+        let list = |items: &[String]| format!("[\n    {}\n  ]", items.join(",\n    "));
+        let mut properties = vec![
+            "\"asset\": {\"version\": \"2.0\", \"generator\": \"medial_axis_3d\"}".to_string(),
+            "\"scene\": 0".to_string(),
+        ];
+        // the spec does not allow empty arrays, so whatever has no items is left out
+        if self.scene.is_empty() {
+            properties.push("\"scenes\": [{}]".to_string());
+        } else {
+            properties.push(format!("\"scenes\": [{{\"nodes\": {:?}}}]", self.scene));
+            properties.push(format!("\"nodes\": {}", list(&self.nodes)));
+        }
+        if !self.meshes.is_empty() {
+            properties.push(format!("\"meshes\": {}", list(&self.meshes)));
+        }
+        if !self.skins.is_empty() {
+            properties.push(format!("\"skins\": {}", list(&self.skins)));
+        }
+        if !self.buffer.is_empty() {
+            properties.push(format!("\"accessors\": {}", list(&self.accessors)));
+            properties.push(format!("\"bufferViews\": {}", list(&self.buffer_views)));
+            properties.push(format!(
+                "\"buffers\": [{{\"byteLength\": {}{}}}]",
+                self.buffer.len(),
+                uri
+            ));
+        }
+        format!("{{\n  {}\n}}\n", properties.join(",\n  "))
+    }
+}
+
+// values are written as f32, and those that are not finite as one have no json representation
+fn check_finite(document: &Document) -> io::Result<()> {
+    let faces = document
+        .faces
+        .iter()
+        .flat_map(|face| vec![face.p1, face.p2, face.p3]);
+    let nodes = document
+        .skeleton
+        .iter()
+        .flat_map(|skeleton| skeleton.nodes.iter().copied());
+    let joints = document
+        .armature
+        .iter()
+        .flat_map(|armature| armature.joints.iter().map(|joint| joint.position));
+    let finite = |value: f64| (value as f32).is_finite();
+    if !faces
+        .chain(nodes)
+        .chain(joints)
+        .all(|point| finite(point.x) && finite(point.y) && finite(point.z))
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "coordinates must be finite",
+        ));
+    }
+    if !document
+        .skin
+        .iter()
+        .flat_map(|skin| skin.weights.iter().flatten())
+        .all(|&weight| finite(weight))
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "skinning weights must be finite",
+        ));
+    }
+    Ok(())
+}
+
+fn build(document: &Document) -> io::Result<Builder> {
+    check_finite(document)?;
+    let mut builder = Builder::default();
+
+    if !document.faces.is_empty() {
+        let points = face_vertices(document.faces);
+        let index = points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| (*point, i as u32))
+            .collect::<HashMap<_, _>>();
+        let indices = document
+            .faces
+            .iter()
+            .flat_map(|face| vec![index[&face.p1], index[&face.p2], index[&face.p3]])
+            .collect::<Vec<_>>();
+
+        let positions = builder.positions(&points);
+        let mut attributes = format!("\"POSITION\": {}", positions);
+        let mut skin = String::new();
+        if let (Some(weights), Some(armature)) = (document.skin, document.armature) {
+            if !armature.joints.is_empty() && weights.joints.len() == points.len() {
+                let joints = weights
+                    .joints
+                    .iter()
+                    .flatten()
+                    .flat_map(|&joint| (joint as u16).to_le_bytes().to_vec())
+                    .collect::<Vec<_>>();
+                let weights = weights
+                    .weights
+                    .iter()
+                    .flatten()
+                    .flat_map(|&weight| (weight as f32).to_le_bytes().to_vec())
+                    .collect::<Vec<_>>();
+                let joints = builder.vec4(&joints, UNSIGNED_SHORT, points.len());
+                let weights = builder.vec4(&weights, FLOAT, points.len());
+                attributes.push_str(&format!(
+                    ", \"JOINTS_0\": {}, \"WEIGHTS_0\": {}",
+                    joints, weights
+                ));
+                skin = ", \"skin\": 0".to_string();
+            }
+        }
+        let indices = builder.indices(&indices);
+        let mesh = builder.mesh("surface", &attributes, indices, TRIANGLES);
+        let node = builder.node(format!(
+            "{{\"name\": \"surface\", \"mesh\": {}{}}}",
+            mesh, skin
+        ));
+        builder.scene.push(node);
+    }
+
+    if let Some(skeleton) = document
+        .skeleton
+        .filter(|skeleton| !skeleton.edges.is_empty())
+    {
+        let indices = skeleton
+            .edges
+            .iter()
+            .flat_map(|&(a, b)| vec![a as u32, b as u32])
+            .collect::<Vec<_>>();
+
+        let positions = builder.positions(&skeleton.nodes);
+        let indices = builder.indices(&indices);
+        let mesh = builder.mesh(
+            "medial_axis",
+            &format!("\"POSITION\": {}", positions),
+            indices,
+            LINES,
+        );
+        let node = builder.node(format!("{{\"name\": \"medial_axis\", \"mesh\": {}}}", mesh));
+        builder.scene.push(node);
+    }
+
+    if let Some(armature) = document
+        .armature
+        .filter(|armature| !armature.joints.is_empty())
+    {
+        let first = builder.nodes.len() + 1;
+        let roots = (0..armature.joints.len())
+            .filter(|&i| armature.joints[i].parent.is_none())
+            .map(|i| first + i)
+            .collect::<Vec<_>>();
+        let root = builder.node(format!(
+            "{{\"name\": \"armature\", \"children\": {:?}}}",
+            roots
+        ));
+        builder.scene.push(root);
+
+        for (i, joint) in armature.joints.iter().enumerate() {
+            let origin = joint.parent.map_or(Point::new(0.0, 0.0, 0.0), |parent| {
+                armature.joints[parent].position
+            });
+            let children = armature
+                .children(i)
+                .into_iter()
+                .map(|child| first + child)
+                .collect::<Vec<_>>();
+            let children = if children.is_empty() {
+                String::new()
+            } else {
+                format!(", \"children\": {:?}", children)
+            };
+            builder.node(format!(
+                "{{\"name\": \"joint_{}\", \"translation\": [{}, {}, {}]{}}}",
+                i,
+                (joint.position.x - origin.x) as f32,
+                (joint.position.y - origin.y) as f32,
+                (joint.position.z - origin.z) as f32,
+                children
+            ));
+        }
+
+        let inverse_bind_matrices = armature
+            .joints
+            .iter()
+            .map(|joint| {
+                #[rustfmt::skip]
+                let matrix = [
+                    1.0, 0.0, 0.0, 0.0,
+                    0.0, 1.0, 0.0, 0.0,
+                    0.0, 0.0, 1.0, 0.0,
+                    -joint.position.x as f32, -joint.position.y as f32, -joint.position.z as f32, 1.0,
+                ];
+                matrix
+            })
+            .collect::<Vec<_>>();
+        let inverse_bind_matrices = builder.matrices(&inverse_bind_matrices);
+        let joints = (0..armature.joints.len())
+            .map(|i| first + i)
+            .collect::<Vec<_>>();
+        builder.skins.push(format!(
+            "{{\"name\": \"armature\", \"inverseBindMatrices\": {}, \"joints\": {:?}}}",
+            inverse_bind_matrices, joints
+        ));
+    }
+
+    Ok(builder)
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+pub fn save<P: AsRef<Path>>(path: P, document: &Document) -> io::Result<()> {
+    let path = path.as_ref();
+    check_finite(document)?;
+    let writer = BufWriter::new(File::create(path)?);
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("glb") => write_glb(writer, document),
+        _ => write_gltf(writer, document),
+    }
+}
+
+pub fn write_gltf<W: Write>(mut writer: W, document: &Document) -> io::Result<()> {
+    let builder = build(document)?;
+    let uri = format!(
+        "data:application/octet-stream;base64,{}",
+        base64(&builder.buffer)
+    );
+    writer.write_all(builder.json(Some(uri)).as_bytes())?;
+    writer.flush()
+}
+
+pub fn write_glb<W: Write>(mut writer: W, document: &Document) -> io::Result<()> {
+    let mut builder = build(document)?;
+    let mut json = builder.json(None).into_bytes();
+    while !json.len().is_multiple_of(4) {
+        json.push(b' ');
+    }
+    while !builder.buffer.len().is_multiple_of(4) {
+        builder.buffer.push(0);
+    }
+
+    // the binary chunk is optional and left out along with the buffer when there is no data
+    let binary = if builder.buffer.is_empty() {
+        0
+    } else {
+        8 + builder.buffer.len()
+    };
+    let total = 12 + 8 + json.len() + binary;
+    writer.write_all(b"glTF")?;
+    writer.write_all(&2u32.to_le_bytes())?;
+    writer.write_all(&(total as u32).to_le_bytes())?;
+    writer.write_all(&(json.len() as u32).to_le_bytes())?;
+    writer.write_all(b"JSON")?;
+    writer.write_all(&json)?;
+    if binary > 0 {
+        writer.write_all(&(builder.buffer.len() as u32).to_le_bytes())?;
+        writer.write_all(b"BIN\0")?;
+        writer.write_all(&builder.buffer)?;
+    }
+    writer.flush()
+}
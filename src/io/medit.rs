@@ -0,0 +1,97 @@
+use super::{TetMesh, TetMeshError, Tokens};
+use crate::point::Point;
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+pub fn load<P: AsRef<Path>>(path: P) -> Result<TetMesh, TetMeshError> {
+    parse(&fs::read_to_string(path)?)
+}
+
+pub fn parse(text: &str) -> Result<TetMesh, TetMeshError> {
+    let mut tokens = Tokens::new(text, Some('#'));
+    let mut mesh = TetMesh::default();
+
+    while let Some(keyword) = tokens.peek() {
+        tokens.next()?;
+        match keyword {
+            "MeshVersionFormatted" | "Dimension" => {
+                let value = tokens.parse::<usize>("value")?;
+                if keyword == "Dimension" && value != 3 {
+                    return Err(tokens.error(format!("unsupported dimension {}", value)));
+                }
+            }
+            "Vertices" => {
+                let count = tokens.parse::<usize>("vertex count")?;
+                for _ in 0..count {
+                    let x = tokens.parse("coordinate")?;
+                    let y = tokens.parse("coordinate")?;
+                    let z = tokens.parse("coordinate")?;
+                    tokens.parse::<i64>("reference")?;
+                    mesh.points.push(Point::new(x, y, z));
+                }
+            }
+            "Tetrahedra" => {
+                let count = tokens.parse::<usize>("tetrahedron count")?;
+                for _ in 0..count {
+                    let mut tetrahedron = [0; 4];
+                    for index in tetrahedron.iter_mut() {
+                        *index = tokens.index(1, mesh.points.len())?;
+                    }
+                    tokens.parse::<i64>("reference")?;
+                    mesh.tetrahedra.push(tetrahedron);
+                }
+            }
+            "Corners" | "RequiredVertices" | "Ridges" | "RequiredEdges" => skip(&mut tokens, 1)?,
+            "Edges" => skip(&mut tokens, 3)?,
+            "Triangles" | "Normals" | "Tangents" => skip(&mut tokens, 4)?,
+            "Quadrilaterals" => skip(&mut tokens, 5)?,
+            "Hexahedra" => skip(&mut tokens, 9)?,
+            "End" => break,
+            _ => return Err(tokens.error(format!("unsupported keyword `{}`", keyword))),
+        }
+    }
+
+    Ok(mesh)
+}
+
+fn skip(tokens: &mut Tokens, values_per_entry: usize) -> Result<(), TetMeshError> {
+    let count = tokens.parse::<usize>("entry count")?;
+    for _ in 0..count * values_per_entry {
+        tokens.next()?;
+    }
+    Ok(())
+}
+
+pub fn save<P: AsRef<Path>>(path: P, mesh: &TetMesh) -> io::Result<()> {
+    write(BufWriter::new(File::create(path)?), mesh)
+}
+
+pub fn write<W: Write>(mut writer: W, mesh: &TetMesh) -> io::Result<()> {
+    writeln!(writer, "MeshVersionFormatted 2")?;
+    writeln!(writer, "Dimension 3")?;
+
+    writeln!(writer, "Vertices")?;
+    writeln!(writer, "{}", mesh.points.len())?;
+    for point in mesh.points.iter() {
+        writeln!(writer, "{} {} {} 0", point.x, point.y, point.z)?;
+    }
+
+    writeln!(writer, "Tetrahedra")?;
+    writeln!(writer, "{}", mesh.tetrahedra.len())?;
+    for [a, b, c, d] in mesh.tetrahedra.iter() {
+        writeln!(writer, "{} {} {} {} 0", a + 1, b + 1, c + 1, d + 1)?;
+    }
+
+    let boundary = mesh.boundary_faces();
+    writeln!(writer, "Triangles")?;
+    writeln!(writer, "{}", boundary.len())?;
+    for [a, b, c] in boundary.iter() {
+        writeln!(writer, "{} {} {} 1", a + 1, b + 1, c + 1)?;
+    }
+
+    writeln!(writer, "End")?;
+    writer.flush()
+}
@@ -0,0 +1,289 @@
+pub mod gltf;
+pub mod medit;
+pub mod obj;
+pub mod ply;
+pub mod skeleton;
+pub mod skin;
+pub mod spheres;
+pub mod stl;
+pub mod tetgen;
+pub mod vtk;
+pub mod xyz;
+
+use crate::{face::Face, point::Point, tetrahedron::Tetrahedron};
+use std::{collections::HashMap, error::Error, fmt, io, path::Path, str::FromStr};
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mesh {
+    pub points: Vec<Point>,
+    pub faces: Vec<Face>,
+}
+
+impl From<obj::Obj> for Mesh {
+    fn from(obj: obj::Obj) -> Self {
+        Self {
+            points: obj.points,
+            faces: obj.faces,
+        }
+    }
+}
+
+impl From<&Mesh> for obj::Obj {
+    fn from(mesh: &Mesh) -> Self {
+        Self {
+            points: mesh.points.clone(),
+            faces: mesh.faces.clone(),
+            ..Self::default()
+        }
+    }
+}
+
+pub fn load_mesh<P: AsRef<Path>>(path: P) -> Result<Mesh, Box<dyn Error>> {
+    let path = path.as_ref();
+    match extension(path).as_deref() {
+        Some("ply") => Ok(ply::load(path)?.into()),
+        Some("stl") => Ok(stl::load(path)?),
+        Some("xyz") | Some("txt") | Some("csv") | Some("pts") => Ok(xyz::load(path)?.into()),
+        Some("obj") => Ok(obj::load(path)?.into()),
+        _ => Err(unsupported(path).into()),
+    }
+}
+
+pub fn save_mesh<P: AsRef<Path>>(path: P, mesh: &Mesh) -> io::Result<()> {
+    let path = path.as_ref();
+    match extension(path).as_deref() {
+        Some("ply") => ply::save(path, &mesh.into(), ply::Format::BinaryLittleEndian),
+        Some("xyz") | Some("txt") | Some("csv") | Some("pts") => xyz::save(path, &mesh.into()),
+        Some("obj") => obj::save(path, &mesh.into()),
+        _ => Err(unsupported(path)),
+    }
+}
+
+fn unsupported(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("unsupported file extension in {}", path.display()),
+    )
+}
+
+fn extension(path: &Path) -> Option<String> {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_lowercase)
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TetMesh {
+    pub points: Vec<Point>,
+    pub tetrahedra: Vec<[usize; 4]>,
+}
+
+impl TetMesh {
+    pub fn from_tetrahedra(tetrahedrons: &[Tetrahedron]) -> Self {
+        let mut mesh = Self::default();
+        let mut indices = HashMap::new();
+        for tetra in tetrahedrons {
+            let mut index = |point: Point| {
+                *indices.entry(point).or_insert_with(|| {
+                    mesh.points.push(point);
+                    mesh.points.len() - 1
+                })
+            };
+            let tetrahedron = [
+                index(tetra.p1),
+                index(tetra.p2),
+                index(tetra.p3),
+                index(tetra.p4),
+            ];
+            mesh.tetrahedra.push(tetrahedron);
+        }
+        mesh
+    }
+
+    pub fn tetrahedra(&self) -> Vec<Tetrahedron> {
+        self.tetrahedra
+            .iter()
+            .map(|&[a, b, c, d]| {
+                Tetrahedron::new(
+                    self.points[a],
+                    self.points[b],
+                    self.points[c],
+                    self.points[d],
+                )
+            })
+            .collect()
+    }
+
+    pub fn boundary_faces(&self) -> Vec<[usize; 3]> {
+        let mut faces = HashMap::new();
+        for &[a, b, c, d] in self.tetrahedra.iter() {
+            for &(face, opposite) in [
+                ([a, b, c], d),
+                ([a, b, d], c),
+                ([a, c, d], b),
+                ([b, c, d], a),
+            ]
+            .iter()
+            {
+                let mut key = face;
+                key.sort_unstable();
+                faces
+                    .entry(key)
+                    .and_modify(|entry: &mut Option<_>| *entry = None)
+                    .or_insert(Some((face, opposite)));
+            }
+        }
+
+        let mut boundary = faces
+            .into_values()
+            .flatten()
+            .map(|([a, b, c], d)| {
+                let face = Face::new(self.points[a], self.points[b], self.points[c]);
+                let normal = face.normal();
+                let (p, q) = (self.points[d], self.points[a]);
+                let towards =
+                    normal.x * (p.x - q.x) + normal.y * (p.y - q.y) + normal.z * (p.z - q.z);
+                if towards > 0.0 {
+                    [a, c, b]
+                } else {
+                    [a, b, c]
+                }
+            })
+            .collect::<Vec<_>>();
+        boundary.sort_unstable();
+        boundary
+    }
+}
+
+pub fn load_tet_mesh<P: AsRef<Path>>(path: P) -> Result<TetMesh, TetMeshError> {
+    let path = path.as_ref();
+    match extension(path).as_deref() {
+        Some("vtk") => vtk::load(path),
+        Some("mesh") => medit::load(path),
+        Some("node") | Some("ele") | Some("face") => tetgen::load(path.with_extension("")),
+        _ => Err(unsupported(path).into()),
+    }
+}
+
+pub fn save_tet_mesh<P: AsRef<Path>>(path: P, mesh: &TetMesh) -> io::Result<()> {
+    let path = path.as_ref();
+    match extension(path).as_deref() {
+        Some("vtk") => vtk::save(path, mesh),
+        Some("mesh") => medit::save(path, mesh),
+        Some("node") | Some("ele") | Some("face") => tetgen::save(path.with_extension(""), mesh),
+        _ => Err(unsupported(path)),
+    }
+}
+
+#[derive(Debug)]
+pub enum TetMeshError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for TetMeshError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TetMeshError::Io(error) => write!(f, "{}", error),
+            TetMeshError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl Error for TetMeshError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TetMeshError::Io(error) => Some(error),
+            TetMeshError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for TetMeshError {
+    fn from(error: io::Error) -> Self {
+        TetMeshError::Io(error)
+    }
+}
+
+struct Tokens<'a> {
+    tokens: Vec<(usize, &'a str)>,
+    position: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(text: &'a str, comment: Option<char>) -> Self {
+        let tokens = text
+            .lines()
+            .enumerate()
+            .flat_map(|(number, line)| {
+                let line = match comment {
+                    Some(comment) => line.split(comment).next().unwrap_or_default(),
+                    None => line,
+                };
+                line.split_whitespace()
+                    .map(move |token| (number + 1, token))
+            })
+            .collect();
+        Self {
+            tokens,
+            position: 0,
+        }
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .or_else(|| self.tokens.last())
+            .map_or(0, |&(line, _)| line)
+    }
+
+    fn error(&self, message: String) -> TetMeshError {
+        TetMeshError::Parse {
+            line: self.line(),
+            message,
+        }
+    }
+
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.position).map(|&(_, token)| token)
+    }
+
+    fn next(&mut self) -> Result<&'a str, TetMeshError> {
+        let token = self
+            .peek()
+            .ok_or_else(|| self.error("unexpected end of file".to_string()))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn parse<T: FromStr>(&mut self, what: &str) -> Result<T, TetMeshError> {
+        let token = self.next()?;
+        token.parse().map_err(|_| TetMeshError::Parse {
+            line: self.tokens[self.position - 1].0,
+            message: format!("invalid {} `{}`", what, token),
+        })
+    }
+
+    fn skip_line(&mut self) {
+        let line = match self.position.checked_sub(1) {
+            Some(previous) => self.tokens[previous].0,
+            None => return,
+        };
+        while self.tokens.get(self.position).map(|&(l, _)| l) == Some(line) {
+            self.position += 1;
+        }
+    }
+
+    fn index(&mut self, base: usize, count: usize) -> Result<usize, TetMeshError> {
+        let index = self.parse::<usize>("index")?;
+        index
+            .checked_sub(base)
+            .filter(|&index| index < count)
+            .ok_or_else(|| TetMeshError::Parse {
+                line: self.tokens[self.position - 1].0,
+                message: format!("index {} out of range", index),
+            })
+    }
+}
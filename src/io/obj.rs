@@ -0,0 +1,256 @@
+use crate::{edge::Edge, face::Face, point::Point, polygon::Polygon};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GroupKind {
+    Object,
+    Group,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Group {
+    pub kind: GroupKind,
+    pub name: String,
+    pub first_face: usize,
+    pub first_line: usize,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Obj {
+    pub points: Vec<Point>,
+    pub faces: Vec<Face>,
+    pub lines: Vec<Edge>,
+    pub groups: Vec<Group>,
+}
+
+#[derive(Debug)]
+pub enum ObjError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for ObjError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ObjError::Io(error) => write!(f, "{}", error),
+            ObjError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl Error for ObjError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ObjError::Io(error) => Some(error),
+            ObjError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ObjError {
+    fn from(error: io::Error) -> Self {
+        ObjError::Io(error)
+    }
+}
+
+pub fn load<P: AsRef<Path>>(path: P) -> Result<Obj, ObjError> {
+    read(BufReader::new(File::open(path)?))
+}
+
+pub fn read<R: BufRead>(reader: R) -> Result<Obj, ObjError> {
+    let mut obj = Obj::default();
+    let mut statement = String::new();
+    let mut first_line = 0;
+
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+        if statement.is_empty() {
+            first_line = number + 1;
+        }
+
+        let line = line.split('#').next().unwrap_or_default().trim_end();
+        if let Some(continued) = line.strip_suffix('\\') {
+            statement.push_str(continued);
+            statement.push(' ');
+            continue;
+        }
+        statement.push_str(line);
+
+        parse_statement(&statement, &mut obj).map_err(|message| ObjError::Parse {
+            line: first_line,
+            message,
+        })?;
+        statement.clear();
+    }
+
+    if !statement.is_empty() {
+        parse_statement(&statement, &mut obj).map_err(|message| ObjError::Parse {
+            line: first_line,
+            message,
+        })?;
+    }
+
+    Ok(obj)
+}
+
+fn parse_coordinate(token: Option<&str>, name: &str) -> Result<f64, String> {
+    let token = token.ok_or_else(|| format!("missing {} coordinate", name))?;
+    token
+        .parse()
+        .map_err(|_| format!("invalid {} coordinate `{}`", name, token))
+}
+
+fn parse_index(token: &str, points: &[Point]) -> Result<Point, String> {
+    let vertex = token.split('/').next().unwrap_or_default();
+    let index = vertex
+        .parse::<isize>()
+        .map_err(|_| format!("invalid vertex index `{}`", token))?;
+
+    let resolved = match index {
+        0 => None,
+        i if i > 0 => Some(i as usize - 1),
+        i => points.len().checked_sub(i.unsigned_abs()),
+    };
+
+    resolved
+        .and_then(|i| points.get(i))
+        .copied()
+        .ok_or_else(|| {
+            format!(
+                "vertex index {} out of range ({} vertices defined)",
+                index,
+                points.len()
+            )
+        })
+}
+
+fn parse_statement(statement: &str, obj: &mut Obj) -> Result<(), String> {
+    let mut tokens = statement.split_whitespace();
+    let keyword = match tokens.next() {
+        Some(keyword) => keyword,
+        None => return Ok(()),
+    };
+
+    match keyword {
+        "v" => {
+            let x = parse_coordinate(tokens.next(), "x")?;
+            let y = parse_coordinate(tokens.next(), "y")?;
+            let z = parse_coordinate(tokens.next(), "z")?;
+            obj.points.push(Point::new(x, y, z));
+        }
+        "f" => {
+            let points = tokens
+                .map(|token| parse_index(token, &obj.points))
+                .collect::<Result<Vec<_>, _>>()?;
+            if points.len() < 3 {
+                return Err(format!(
+                    "face has {} vertices, expected at least 3",
+                    points.len()
+                ));
+            }
+            let triangles = Polygon::new(points)
+                .triangulate()
+                .ok_or_else(|| "face crosses itself".to_string())?;
+            obj.faces.extend(triangles);
+        }
+        "l" => {
+            let points = tokens
+                .map(|token| parse_index(token, &obj.points))
+                .collect::<Result<Vec<_>, _>>()?;
+            if points.len() < 2 {
+                return Err(format!(
+                    "line has {} vertices, expected at least 2",
+                    points.len()
+                ));
+            }
+            obj.lines
+                .extend(points.windows(2).map(|pair| Edge::new(pair[0], pair[1])));
+        }
+        "o" | "g" => {
+            obj.groups.push(Group {
+                kind: if keyword == "o" {
+                    GroupKind::Object
+                } else {
+                    GroupKind::Group
+                },
+                name: tokens.collect::<Vec<_>>().join(" "),
+                first_face: obj.faces.len(),
+                first_line: obj.lines.len(),
+            });
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+pub fn save<P: AsRef<Path>>(path: P, obj: &Obj) -> io::Result<()> {
+    write(BufWriter::new(File::create(path)?), obj)
+}
+
+pub fn write<W: Write>(mut writer: W, obj: &Obj) -> io::Result<()> {
+    let mut indices = HashMap::new();
+    let mut points = Vec::new();
+    let face_points = obj
+        .faces
+        .iter()
+        .flat_map(|face| vec![face.p1, face.p2, face.p3]);
+    let line_points = obj.lines.iter().flat_map(|line| vec![line.p1, line.p2]);
+    for point in obj
+        .points
+        .iter()
+        .copied()
+        .chain(face_points)
+        .chain(line_points)
+    {
+        indices.entry(point).or_insert_with(|| {
+            points.push(point);
+            points.len()
+        });
+    }
+
+    for Point { x, y, z } in points.iter() {
+        writeln!(writer, "v {} {} {}", x, y, z)?;
+    }
+
+    let mut groups = obj.groups.iter().peekable();
+    let (mut face, mut line) = (0, 0);
+    loop {
+        let (face_end, line_end) = groups
+            .peek()
+            .map(|group| (group.first_face, group.first_line))
+            .unwrap_or((obj.faces.len(), obj.lines.len()));
+
+        for Face { p1, p2, p3 } in obj.faces[face..face_end].iter() {
+            writeln!(writer, "f {} {} {}", indices[p1], indices[p2], indices[p3])?;
+        }
+        for Edge { p1, p2 } in obj.lines[line..line_end].iter() {
+            writeln!(writer, "l {} {}", indices[p1], indices[p2])?;
+        }
+        face = face_end;
+        line = line_end;
+
+        match groups.next() {
+            Some(group) => {
+                let keyword = match group.kind {
+                    GroupKind::Object => "o",
+                    GroupKind::Group => "g",
+                };
+                writeln!(writer, "{} {}", keyword, group.name)?;
+            }
+            None => break,
+        }
+    }
+
+    writer.flush()
+}
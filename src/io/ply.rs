@@ -0,0 +1,651 @@
+use super::Mesh;
+use crate::{face::Face, point::Point, polygon::Polygon, skeleton::Skeleton};
+use std::{
+    collections::HashMap,
+    convert::TryInto,
+    error::Error,
+    fmt, fs,
+    fs::File,
+    io::{self, BufWriter, Read, Write},
+    path::Path,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Format {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ply {
+    pub points: Vec<Point>,
+    pub properties: Vec<(String, Vec<f64>)>,
+    pub faces: Vec<Vec<usize>>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl Ply {
+    pub fn property(&self, name: &str) -> Option<&[f64]> {
+        self.properties
+            .iter()
+            .find(|(property, _)| property == name)
+            .map(|(_, values)| &values[..])
+    }
+
+    pub fn normals(&self) -> Option<Vec<Point>> {
+        let (nx, ny, nz) = (
+            self.property("nx")?,
+            self.property("ny")?,
+            self.property("nz")?,
+        );
+        Some(
+            nx.iter()
+                .zip(ny)
+                .zip(nz)
+                .map(|((&x, &y), &z)| Point::new(x, y, z))
+                .collect(),
+        )
+    }
+
+    pub fn triangles(&self) -> Vec<Face> {
+        self.faces
+            .iter()
+            .flat_map(|face| {
+                Polygon::new(face.iter().map(|&i| self.points[i]).collect())
+                    .triangulate()
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+}
+
+impl From<Ply> for Mesh {
+    fn from(ply: Ply) -> Self {
+        Self {
+            faces: ply.triangles(),
+            points: ply.points,
+        }
+    }
+}
+
+impl From<&Mesh> for Ply {
+    fn from(mesh: &Mesh) -> Self {
+        let mut ply = Self {
+            points: mesh.points.clone(),
+            ..Self::default()
+        };
+        let mut indices = mesh
+            .points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| (*point, i))
+            .collect::<HashMap<_, _>>();
+        for face in mesh.faces.iter() {
+            let face = [face.p1, face.p2, face.p3]
+                .iter()
+                .map(|&point| {
+                    *indices.entry(point).or_insert_with(|| {
+                        ply.points.push(point);
+                        ply.points.len() - 1
+                    })
+                })
+                .collect();
+            ply.faces.push(face);
+        }
+        ply
+    }
+}
+
+impl From<&Skeleton> for Ply {
+    fn from(skeleton: &Skeleton) -> Self {
+        Self {
+            points: skeleton.nodes.clone(),
+            properties: vec![("radius".to_string(), skeleton.radii.clone())],
+            faces: Vec::new(),
+            edges: skeleton.edges.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PlyError {
+    Io(io::Error),
+    Header {
+        line: usize,
+        message: String,
+    },
+    Data {
+        element: String,
+        index: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for PlyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PlyError::Io(error) => write!(f, "{}", error),
+            PlyError::Header { line, message } => write!(f, "header line {}: {}", line, message),
+            PlyError::Data {
+                element,
+                index,
+                message,
+            } => write!(f, "{} {}: {}", element, index, message),
+        }
+    }
+}
+
+impl Error for PlyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PlyError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PlyError {
+    fn from(error: io::Error) -> Self {
+        PlyError::Io(error)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ScalarType {
+    Int8,
+    UInt8,
+    Int16,
+    UInt16,
+    Int32,
+    UInt32,
+    Float32,
+    Float64,
+}
+
+impl ScalarType {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "char" | "int8" => ScalarType::Int8,
+            "uchar" | "uint8" => ScalarType::UInt8,
+            "short" | "int16" => ScalarType::Int16,
+            "ushort" | "uint16" => ScalarType::UInt16,
+            "int" | "int32" => ScalarType::Int32,
+            "uint" | "uint32" => ScalarType::UInt32,
+            "float" | "float32" => ScalarType::Float32,
+            "double" | "float64" => ScalarType::Float64,
+            _ => return None,
+        })
+    }
+
+    fn size(self) -> usize {
+        match self {
+            ScalarType::Int8 | ScalarType::UInt8 => 1,
+            ScalarType::Int16 | ScalarType::UInt16 => 2,
+            ScalarType::Int32 | ScalarType::UInt32 | ScalarType::Float32 => 4,
+            ScalarType::Float64 => 8,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum PropertyType {
+    Scalar(ScalarType),
+    List(ScalarType, ScalarType),
+}
+
+#[derive(Clone, Debug)]
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<(String, PropertyType)>,
+}
+
+struct Header {
+    format: Format,
+    elements: Vec<Element>,
+    size: usize,
+    lines: usize,
+}
+
+fn parse_header(bytes: &[u8]) -> Result<Header, PlyError> {
+    let mut format = None;
+    let mut elements: Vec<Element> = Vec::new();
+    let mut offset = 0;
+
+    for number in 1.. {
+        let error = |message: String| PlyError::Header {
+            line: number,
+            message,
+        };
+
+        let end = bytes[offset..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .ok_or_else(|| error("missing `end_header`".to_string()))?;
+        let line = std::str::from_utf8(&bytes[offset..offset + end])
+            .map_err(|_| error("invalid utf-8".to_string()))?
+            .trim();
+        offset += end + 1;
+
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+        match tokens[..] {
+            ["ply"] if number == 1 => {}
+            _ if number == 1 => return Err(error("missing `ply` magic".to_string())),
+            ["format", name, _version] => {
+                format = Some(match name {
+                    "ascii" => Format::Ascii,
+                    "binary_little_endian" => Format::BinaryLittleEndian,
+                    "binary_big_endian" => Format::BinaryBigEndian,
+                    _ => return Err(error(format!("unknown format `{}`", name))),
+                })
+            }
+            ["comment", ..] | ["obj_info", ..] | [] => {}
+            ["element", name, count] => elements.push(Element {
+                name: name.to_string(),
+                count: count
+                    .parse()
+                    .map_err(|_| error(format!("invalid element count `{}`", count)))?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", count_type, item_type, name] => {
+                let scalar = |name: &str| {
+                    ScalarType::parse(name).ok_or_else(|| error(format!("unknown type `{}`", name)))
+                };
+                let property = PropertyType::List(scalar(count_type)?, scalar(item_type)?);
+                elements
+                    .last_mut()
+                    .ok_or_else(|| error("property before any element".to_string()))?
+                    .properties
+                    .push((name.to_string(), property));
+            }
+            ["property", scalar_type, name] => {
+                let property = PropertyType::Scalar(
+                    ScalarType::parse(scalar_type)
+                        .ok_or_else(|| error(format!("unknown type `{}`", scalar_type)))?,
+                );
+                elements
+                    .last_mut()
+                    .ok_or_else(|| error("property before any element".to_string()))?
+                    .properties
+                    .push((name.to_string(), property));
+            }
+            ["end_header"] => {
+                let vertex = elements.iter().find(|element| element.name == "vertex");
+                if let Some(vertex) = vertex {
+                    for &axis in ["x", "y", "z"].iter() {
+                        if !vertex.properties.iter().any(|(name, property)| {
+                            name == axis && matches!(property, PropertyType::Scalar(_))
+                        }) {
+                            return Err(error(format!("missing vertex property `{}`", axis)));
+                        }
+                    }
+                }
+                return Ok(Header {
+                    format: format.ok_or_else(|| error("missing `format` line".to_string()))?,
+                    elements,
+                    size: offset,
+                    lines: number,
+                });
+            }
+            _ => return Err(error(format!("unexpected header line `{}`", line))),
+        }
+    }
+
+    unreachable!()
+}
+
+enum Data<'a> {
+    Ascii {
+        tokens: Vec<(usize, &'a str)>,
+        position: usize,
+    },
+    Binary {
+        bytes: &'a [u8],
+        offset: usize,
+        big_endian: bool,
+    },
+}
+
+impl<'a> Data<'a> {
+    fn scalar(&mut self, scalar_type: ScalarType) -> Result<f64, String> {
+        match self {
+            Data::Ascii { tokens, position } => {
+                let &(line, token) = tokens.get(*position).ok_or("unexpected end of data")?;
+                *position += 1;
+                token
+                    .parse()
+                    .map_err(|_| format!("invalid number `{}` on line {}", token, line))
+            }
+            Data::Binary {
+                bytes,
+                offset,
+                big_endian,
+            } => {
+                let size = scalar_type.size();
+                let mut raw = bytes
+                    .get(*offset..*offset + size)
+                    .ok_or("unexpected end of data")?
+                    .to_vec();
+                *offset += size;
+                if *big_endian {
+                    raw.reverse();
+                }
+                Ok(match scalar_type {
+                    ScalarType::Int8 => raw[0] as i8 as f64,
+                    ScalarType::UInt8 => raw[0] as f64,
+                    ScalarType::Int16 => i16::from_le_bytes(raw[..].try_into().unwrap()) as f64,
+                    ScalarType::UInt16 => u16::from_le_bytes(raw[..].try_into().unwrap()) as f64,
+                    ScalarType::Int32 => i32::from_le_bytes(raw[..].try_into().unwrap()) as f64,
+                    ScalarType::UInt32 => u32::from_le_bytes(raw[..].try_into().unwrap()) as f64,
+                    ScalarType::Float32 => f32::from_le_bytes(raw[..].try_into().unwrap()) as f64,
+                    ScalarType::Float64 => f64::from_le_bytes(raw[..].try_into().unwrap()),
+                })
+            }
+        }
+    }
+
+    // where the last value was read from
+    fn location(&self) -> String {
+        match self {
+            Data::Ascii { tokens, position } => format!("line {}", tokens[*position - 1].0),
+            Data::Binary { offset, .. } => format!("byte {}", offset),
+        }
+    }
+
+    // a count or index, which casting would saturate when negative, fractional or not a number
+    fn index(&mut self, scalar_type: ScalarType, what: &str) -> Result<usize, String> {
+        let value = self.scalar(scalar_type)?;
+        if value >= 0.0 && value.fract() == 0.0 {
+            Ok(value as usize)
+        } else {
+            Err(format!("invalid {} {} on {}", what, value, self.location()))
+        }
+    }
+
+    fn list(&mut self, count_type: ScalarType, item_type: ScalarType) -> Result<Vec<f64>, String> {
+        let count = self.index(count_type, "list count")?;
+        (0..count).map(|_| self.scalar(item_type)).collect()
+    }
+
+    fn indices(
+        &mut self,
+        count_type: ScalarType,
+        item_type: ScalarType,
+    ) -> Result<Vec<usize>, String> {
+        let count = self.index(count_type, "list count")?;
+        (0..count)
+            .map(|_| self.index(item_type, "vertex index"))
+            .collect()
+    }
+}
+
+pub fn load<P: AsRef<Path>>(path: P) -> Result<Ply, PlyError> {
+    parse(&fs::read(path)?)
+}
+
+pub fn read<R: Read>(mut reader: R) -> Result<Ply, PlyError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    parse(&bytes)
+}
+
+pub fn parse(bytes: &[u8]) -> Result<Ply, PlyError> {
+    let header = parse_header(bytes)?;
+    let mut data = match header.format {
+        Format::Ascii => Data::Ascii {
+            tokens: std::str::from_utf8(&bytes[header.size..])
+                .map_err(|_| PlyError::Data {
+                    element: header
+                        .elements
+                        .first()
+                        .map(|e| e.name.clone())
+                        .unwrap_or_default(),
+                    index: 0,
+                    message: "invalid utf-8 in ascii data".to_string(),
+                })?
+                .lines()
+                .enumerate()
+                .flat_map(|(number, line)| {
+                    let number = header.lines + number + 1;
+                    line.split_whitespace().map(move |token| (number, token))
+                })
+                .collect(),
+            position: 0,
+        },
+        format => Data::Binary {
+            bytes,
+            offset: header.size,
+            big_endian: format == Format::BinaryBigEndian,
+        },
+    };
+
+    let mut ply = Ply::default();
+    for element in header.elements.iter() {
+        if element.name == "vertex" {
+            ply.properties = element
+                .properties
+                .iter()
+                .filter(|(name, property)| {
+                    !matches!(name.as_str(), "x" | "y" | "z")
+                        && matches!(property, PropertyType::Scalar(_))
+                })
+                .map(|(name, _)| (name.clone(), Vec::with_capacity(element.count)))
+                .collect();
+        }
+
+        for index in 0..element.count {
+            let error = |message: String| PlyError::Data {
+                element: element.name.clone(),
+                index,
+                message,
+            };
+
+            let mut point = Point::new(0.0, 0.0, 0.0);
+            let mut indices = None;
+            let mut edge = (0, 0);
+            for (name, property) in element.properties.iter() {
+                match (property, element.name.as_str(), name.as_str()) {
+                    (&PropertyType::Scalar(scalar_type), "edge", "vertex1") => {
+                        edge.0 = data.index(scalar_type, "vertex index").map_err(error)?
+                    }
+                    (&PropertyType::Scalar(scalar_type), "edge", "vertex2") => {
+                        edge.1 = data.index(scalar_type, "vertex index").map_err(error)?
+                    }
+                    (&PropertyType::Scalar(scalar_type), element_name, _) => {
+                        let value = data.scalar(scalar_type).map_err(error)?;
+                        match (element_name, name.as_str()) {
+                            ("vertex", "x") => point.x = value,
+                            ("vertex", "y") => point.y = value,
+                            ("vertex", "z") => point.z = value,
+                            ("vertex", _) => ply
+                                .properties
+                                .iter_mut()
+                                .find(|(property, _)| property == name)
+                                .unwrap()
+                                .1
+                                .push(value),
+                            _ => {}
+                        }
+                    }
+                    (&PropertyType::List(count_type, item_type), "face", "vertex_indices")
+                    | (&PropertyType::List(count_type, item_type), "face", "vertex_index") => {
+                        indices = Some(data.indices(count_type, item_type).map_err(error)?);
+                    }
+                    (&PropertyType::List(count_type, item_type), _, _) => {
+                        data.list(count_type, item_type).map_err(error)?;
+                    }
+                }
+            }
+
+            match element.name.as_str() {
+                "vertex" => ply.points.push(point),
+                "face" => {
+                    let indices: Vec<usize> =
+                        indices.ok_or_else(|| error("face without vertex indices".to_string()))?;
+                    if let Some(&i) = indices.iter().find(|&&i| i >= ply.points.len()) {
+                        return Err(error(format!("vertex index {} out of range", i)));
+                    }
+                    if Polygon::new(indices.iter().map(|&i| ply.points[i]).collect())
+                        .triangulate()
+                        .is_none()
+                    {
+                        return Err(error("face crosses itself".to_string()));
+                    }
+                    ply.faces.push(indices);
+                }
+                "edge" => {
+                    if edge.0.max(edge.1) >= ply.points.len() {
+                        return Err(error(format!("edge {:?} out of range", edge)));
+                    }
+                    ply.edges.push(edge);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(ply)
+}
+
+pub fn save<P: AsRef<Path>>(path: P, ply: &Ply, format: Format) -> io::Result<()> {
+    write(BufWriter::new(File::create(path)?), ply, format)
+}
+
+// checked before anything is written, as the format cannot hold these
+fn validate(ply: &Ply) -> io::Result<()> {
+    let invalid = |message: String| Err(io::Error::new(io::ErrorKind::InvalidInput, message));
+
+    if let Some((name, values)) = ply
+        .properties
+        .iter()
+        .find(|(_, values)| values.len() != ply.points.len())
+    {
+        return invalid(format!(
+            "property {} has {} values for {} vertices",
+            name,
+            values.len(),
+            ply.points.len()
+        ));
+    }
+    if let Some(face) = ply.faces.iter().find(|face| face.len() > u8::MAX as usize) {
+        return invalid(format!(
+            "face has {} vertices, more than its uchar count can hold",
+            face.len()
+        ));
+    }
+    if ply.points.len() > i32::MAX as usize {
+        return invalid(format!(
+            "{} vertices cannot be indexed by an int",
+            ply.points.len()
+        ));
+    }
+    if let Some(index) = ply
+        .faces
+        .iter()
+        .flatten()
+        .chain(ply.edges.iter().flat_map(|(a, b)| vec![a, b]))
+        .find(|&&index| index >= ply.points.len())
+    {
+        return invalid(format!(
+            "vertex index {} is out of range for {} vertices",
+            index,
+            ply.points.len()
+        ));
+    }
+    Ok(())
+}
+
+pub fn write<W: Write>(mut writer: W, ply: &Ply, format: Format) -> io::Result<()> {
+    validate(ply)?;
+
+    writeln!(writer, "ply")?;
+    writeln!(
+        writer,
+        "format {} 1.0",
+        match format {
+            Format::Ascii => "ascii",
+            Format::BinaryLittleEndian => "binary_little_endian",
+            Format::BinaryBigEndian => "binary_big_endian",
+        }
+    )?;
+    writeln!(writer, "element vertex {}", ply.points.len())?;
+    for name in ["x", "y", "z"]
+        .iter()
+        .copied()
+        .chain(ply.properties.iter().map(|(name, _)| name.as_str()))
+    {
+        writeln!(writer, "property double {}", name)?;
+    }
+    if !ply.faces.is_empty() {
+        writeln!(writer, "element face {}", ply.faces.len())?;
+        writeln!(writer, "property list uchar int vertex_indices")?;
+    }
+    if !ply.edges.is_empty() {
+        writeln!(writer, "element edge {}", ply.edges.len())?;
+        writeln!(writer, "property int vertex1")?;
+        writeln!(writer, "property int vertex2")?;
+    }
+    writeln!(writer, "end_header")?;
+
+    let vertices = ply.points.iter().enumerate().map(|(i, point)| {
+        [point.x, point.y, point.z]
+            .iter()
+            .copied()
+            .chain(ply.properties.iter().map(|(_, values)| values[i]))
+            .collect::<Vec<_>>()
+    });
+
+    match format {
+        Format::Ascii => {
+            for vertex in vertices {
+                let values = vertex.iter().map(f64::to_string).collect::<Vec<_>>();
+                writeln!(writer, "{}", values.join(" "))?;
+            }
+            for face in ply.faces.iter() {
+                let indices = face.iter().map(usize::to_string).collect::<Vec<_>>();
+                writeln!(writer, "{} {}", face.len(), indices.join(" "))?;
+            }
+            for (a, b) in ply.edges.iter() {
+                writeln!(writer, "{} {}", a, b)?;
+            }
+        }
+        Format::BinaryLittleEndian | Format::BinaryBigEndian => {
+            let big_endian = format == Format::BinaryBigEndian;
+            let double = |value: f64| {
+                if big_endian {
+                    value.to_be_bytes()
+                } else {
+                    value.to_le_bytes()
+                }
+            };
+            let int = |value: usize| {
+                if big_endian {
+                    (value as i32).to_be_bytes()
+                } else {
+                    (value as i32).to_le_bytes()
+                }
+            };
+
+            for vertex in vertices {
+                for value in vertex {
+                    writer.write_all(&double(value))?;
+                }
+            }
+            for face in ply.faces.iter() {
+                writer.write_all(&[face.len() as u8])?;
+                for &index in face.iter() {
+                    writer.write_all(&int(index))?;
+                }
+            }
+            for &(a, b) in ply.edges.iter() {
+                writer.write_all(&int(a))?;
+                writer.write_all(&int(b))?;
+            }
+        }
+    }
+
+    writer.flush()
+}
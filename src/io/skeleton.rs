@@ -0,0 +1,138 @@
+use super::obj::{self, Obj};
+use crate::skeleton::Skeleton;
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+fn number(value: f64) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+pub fn save<P: AsRef<Path>>(path: P, skeleton: &Skeleton) -> io::Result<()> {
+    let path = path.as_ref();
+    let writer = BufWriter::new(File::create(path)?);
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_lowercase);
+
+    match extension.as_deref() {
+        Some("json") => write_json(writer, skeleton),
+        Some("graphml") => write_graphml(writer, skeleton),
+        Some("dot") | Some("gv") => write_dot(writer, skeleton),
+        _ => write_obj(writer, skeleton),
+    }
+}
+
+pub fn write_obj<W: Write>(writer: W, skeleton: &Skeleton) -> io::Result<()> {
+    let obj = Obj {
+        points: skeleton.nodes.clone(),
+        lines: skeleton.segments(),
+        ..Obj::default()
+    };
+    obj::write(writer, &obj)
+}
+
+pub fn write_json<W: Write>(mut writer: W, skeleton: &Skeleton) -> io::Result<()> {
+    writeln!(writer, "{{")?;
+    writeln!(writer, "  \"nodes\": [")?;
+    for (i, (node, &radius)) in skeleton.nodes.iter().zip(skeleton.radii.iter()).enumerate() {
+        let separator = if i + 1 < skeleton.nodes.len() {
+            ","
+        } else {
+            ""
+        };
+        writeln!(
+            writer,
+            "    {{\"id\": {}, \"position\": [{}, {}, {}], \"radius\": {}}}{}",
+            i,
+            number(node.x),
+            number(node.y),
+            number(node.z),
+            number(radius),
+            separator
+        )?;
+    }
+    writeln!(writer, "  ],")?;
+    writeln!(writer, "  \"edges\": [")?;
+    for (i, (source, target)) in skeleton.edges.iter().enumerate() {
+        let separator = if i + 1 < skeleton.edges.len() {
+            ","
+        } else {
+            ""
+        };
+        writeln!(
+            writer,
+            "    {{\"source\": {}, \"target\": {}}}{}",
+            source, target, separator
+        )?;
+    }
+    writeln!(writer, "  ]")?;
+    writeln!(writer, "}}")?;
+    writer.flush()
+}
+
+pub fn write_graphml<W: Write>(mut writer: W, skeleton: &Skeleton) -> io::Result<()> {
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    )?;
+    for key in ["x", "y", "z", "radius"].iter() {
+        writeln!(
+            writer,
+            r#"  <key id="{0}" for="node" attr.name="{0}" attr.type="double"/>"#,
+            key
+        )?;
+    }
+    writeln!(
+        writer,
+        r#"  <graph id="skeleton" edgedefault="undirected">"#
+    )?;
+    for (i, (node, radius)) in skeleton.nodes.iter().zip(skeleton.radii.iter()).enumerate() {
+        writeln!(writer, r#"    <node id="n{}">"#, i)?;
+        for (key, value) in [
+            ("x", node.x),
+            ("y", node.y),
+            ("z", node.z),
+            ("radius", *radius),
+        ]
+        .iter()
+        {
+            writeln!(writer, r#"      <data key="{}">{}</data>"#, key, value)?;
+        }
+        writeln!(writer, "    </node>")?;
+    }
+    for (i, (source, target)) in skeleton.edges.iter().enumerate() {
+        writeln!(
+            writer,
+            r#"    <edge id="e{}" source="n{}" target="n{}"/>"#,
+            i, source, target
+        )?;
+    }
+    writeln!(writer, "  </graph>")?;
+    writeln!(writer, "</graphml>")?;
+    writer.flush()
+}
+
+pub fn write_dot<W: Write>(mut writer: W, skeleton: &Skeleton) -> io::Result<()> {
+    writeln!(writer, "graph skeleton {{")?;
+    for (i, (node, radius)) in skeleton.nodes.iter().zip(skeleton.radii.iter()).enumerate() {
+        writeln!(
+            writer,
+            "  {} [pos=\"{},{},{}\", radius={}];",
+            i, node.x, node.y, node.z, radius
+        )?;
+    }
+    for (source, target) in skeleton.edges.iter() {
+        writeln!(writer, "  {} -- {};", source, target)?;
+    }
+    writeln!(writer, "}}")?;
+    writer.flush()
+}
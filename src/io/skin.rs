@@ -0,0 +1,36 @@
+use crate::{point::Point, skinning::Skin};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+pub fn save<P: AsRef<Path>>(path: P, points: &[Point], skin: &Skin) -> io::Result<()> {
+    write_csv(BufWriter::new(File::create(path)?), points, skin)
+}
+
+pub fn write_csv<W: Write>(mut writer: W, points: &[Point], skin: &Skin) -> io::Result<()> {
+    if skin.joints.len() != points.len() || skin.weights.len() != points.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "{} points but {} joint and {} weight entries",
+                points.len(),
+                skin.joints.len(),
+                skin.weights.len()
+            ),
+        ));
+    }
+    writeln!(
+        writer,
+        "x,y,z,joint0,weight0,joint1,weight1,joint2,weight2,joint3,weight3"
+    )?;
+    for ((point, joints), weights) in points.iter().zip(&skin.joints).zip(&skin.weights) {
+        write!(writer, "{},{},{}", point.x, point.y, point.z)?;
+        for (joint, weight) in joints.iter().zip(weights) {
+            write!(writer, ",{},{}", joint, weight)?;
+        }
+        writeln!(writer)?;
+    }
+    writer.flush()
+}
@@ -0,0 +1,76 @@
+use crate::{point::Point, spheres::Sphere};
+use std::{
+    convert::TryInto,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+const MAGIC: &[u8; 4] = b"MASP";
+const VERSION: u32 = 1;
+
+pub fn save<P: AsRef<Path>>(path: P, spheres: &[Sphere]) -> io::Result<()> {
+    let path = path.as_ref();
+    let writer = BufWriter::new(File::create(path)?);
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => write_json(writer, spheres),
+        _ => write_binary(writer, spheres),
+    }
+}
+
+pub fn write_json<W: Write>(mut writer: W, spheres: &[Sphere]) -> io::Result<()> {
+    writeln!(writer, "{{")?;
+    writeln!(writer, "  \"spheres\": [")?;
+    for (i, Sphere { center, radius }) in spheres.iter().enumerate() {
+        let separator = if i + 1 < spheres.len() { "," } else { "" };
+        writeln!(
+            writer,
+            "    {{\"center\": [{}, {}, {}], \"radius\": {}}}{}",
+            center.x, center.y, center.z, radius, separator
+        )?;
+    }
+    writeln!(writer, "  ]")?;
+    writeln!(writer, "}}")?;
+    writer.flush()
+}
+
+pub fn write_binary<W: Write>(mut writer: W, spheres: &[Sphere]) -> io::Result<()> {
+    writer.write_all(MAGIC)?;
+    writer.write_all(&VERSION.to_le_bytes())?;
+    writer.write_all(&(spheres.len() as u32).to_le_bytes())?;
+    for Sphere { center, radius } in spheres.iter() {
+        for value in [center.x, center.y, center.z, *radius].iter() {
+            writer.write_all(&(*value as f32).to_le_bytes())?;
+        }
+    }
+    writer.flush()
+}
+
+pub fn load_binary<P: AsRef<Path>>(path: P) -> io::Result<Vec<Sphere>> {
+    parse_binary(&fs::read(path)?)
+}
+
+pub fn parse_binary(bytes: &[u8]) -> io::Result<Vec<Sphere>> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+
+    if bytes.len() < 12 || &bytes[..4] != MAGIC {
+        return Err(invalid("missing sphere set header"));
+    }
+    let version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+    if version != VERSION {
+        return Err(invalid("unsupported sphere set version"));
+    }
+    let count = u32::from_le_bytes(bytes[8..12].try_into().unwrap()) as usize;
+    if bytes.len() != 12 + count * 16 {
+        return Err(invalid("sphere count does not match file size"));
+    }
+
+    Ok(bytes[12..]
+        .chunks_exact(16)
+        .map(|sphere| {
+            let value =
+                |i: usize| f32::from_le_bytes(sphere[4 * i..4 * i + 4].try_into().unwrap()) as f64;
+            Sphere::new(Point::new(value(0), value(1), value(2)), value(3))
+        })
+        .collect())
+}
@@ -0,0 +1,230 @@
+use super::Mesh;
+use crate::{
+    face::Face,
+    point::Point,
+    polygon::Polygon,
+    repair::{face_vertices, weld_vertices},
+};
+use std::{
+    convert::TryInto,
+    error::Error,
+    fmt, fs,
+    io::{self, Read},
+    path::Path,
+};
+
+const HEADER_SIZE: usize = 80;
+const FACET_SIZE: usize = 50;
+const WELD_TOLERANCE: f64 = 1e-6;
+
+#[derive(Debug)]
+pub enum StlError {
+    Io(io::Error),
+    Ascii { line: usize, message: String },
+    Binary { message: String },
+}
+
+impl fmt::Display for StlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StlError::Io(error) => write!(f, "{}", error),
+            StlError::Ascii { line, message } => write!(f, "ascii stl, line {}: {}", line, message),
+            StlError::Binary { message } => write!(f, "binary stl: {}", message),
+        }
+    }
+}
+
+impl Error for StlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StlError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for StlError {
+    fn from(error: io::Error) -> Self {
+        StlError::Io(error)
+    }
+}
+
+pub fn load<P: AsRef<Path>>(path: P) -> Result<Mesh, StlError> {
+    parse(&fs::read(path)?)
+}
+
+pub fn read<R: Read>(mut reader: R) -> Result<Mesh, StlError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    parse(&bytes)
+}
+
+pub fn is_binary(bytes: &[u8]) -> bool {
+    if bytes.len() >= HEADER_SIZE + 4 {
+        let count = u32::from_le_bytes(bytes[HEADER_SIZE..HEADER_SIZE + 4].try_into().unwrap());
+        if HEADER_SIZE + 4 + count as usize * FACET_SIZE == bytes.len() {
+            return true;
+        }
+    }
+    !bytes.trim_ascii_start().starts_with(b"solid")
+}
+
+pub fn parse(bytes: &[u8]) -> Result<Mesh, StlError> {
+    let triangles = if is_binary(bytes) {
+        parse_binary(bytes)?
+    } else {
+        let text = std::str::from_utf8(bytes).map_err(|error| StlError::Ascii {
+            line: bytes[..error.valid_up_to()]
+                .iter()
+                .filter(|&&b| b == b'\n')
+                .count()
+                + 1,
+            message: "invalid utf-8".to_string(),
+        })?;
+        parse_ascii(text)?
+    };
+
+    // stl repeats the vertices of every facet, and exporters round them differently from one
+    // facet to the next, so they are welded within a tolerance relative to the size of the mesh
+    let points = face_vertices(&triangles);
+    let (min, max) = points.iter().fold(
+        (
+            Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+            Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+        ),
+        |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z)),
+                Point::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z)),
+            )
+        },
+    );
+    let tolerance = WELD_TOLERANCE * min.distance(&max);
+    let triangles = if tolerance > 0.0 {
+        weld_vertices(&triangles, tolerance)
+            .into_iter()
+            .filter(|Face { p1, p2, p3 }| p1 != p2 && p2 != p3 && p3 != p1)
+            .collect()
+    } else {
+        triangles
+    };
+    Ok(Mesh {
+        points: face_vertices(&triangles),
+        faces: triangles,
+    })
+}
+
+fn point(x: f64, y: f64, z: f64) -> Point {
+    Point::new(x + 0.0, y + 0.0, z + 0.0)
+}
+
+fn parse_binary(bytes: &[u8]) -> Result<Vec<Face>, StlError> {
+    if bytes.len() < HEADER_SIZE + 4 {
+        return Err(StlError::Binary {
+            message: format!(
+                "file is {} bytes, shorter than the 84 byte header",
+                bytes.len()
+            ),
+        });
+    }
+
+    let count =
+        u32::from_le_bytes(bytes[HEADER_SIZE..HEADER_SIZE + 4].try_into().unwrap()) as usize;
+    let expected = HEADER_SIZE + 4 + count * FACET_SIZE;
+    if bytes.len() < expected {
+        return Err(StlError::Binary {
+            message: format!(
+                "header declares {} facets ({} bytes) but file is {} bytes",
+                count,
+                expected,
+                bytes.len()
+            ),
+        });
+    }
+
+    let read_f32 =
+        |offset: usize| f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()) as f64;
+    let read_point =
+        |offset: usize| point(read_f32(offset), read_f32(offset + 4), read_f32(offset + 8));
+
+    Ok((0..count)
+        .map(|i| {
+            let facet = HEADER_SIZE + 4 + i * FACET_SIZE;
+            Face::new(
+                read_point(facet + 12),
+                read_point(facet + 24),
+                read_point(facet + 36),
+            )
+        })
+        .collect())
+}
+
+fn parse_ascii(text: &str) -> Result<Vec<Face>, StlError> {
+    let mut triangles = Vec::new();
+    let mut outline: Option<Vec<Point>> = None;
+
+    for (number, line) in text.lines().enumerate() {
+        let error = |message: String| StlError::Ascii {
+            line: number + 1,
+            message,
+        };
+
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            Some("vertex") => {
+                let coordinates = tokens
+                    .map(|token| {
+                        token
+                            .parse::<f64>()
+                            .map_err(|_| error(format!("invalid coordinate `{}`", token)))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let vertex = match coordinates[..] {
+                    [x, y, z] => point(x, y, z),
+                    _ => {
+                        return Err(error(format!(
+                            "vertex has {} coordinates, expected 3",
+                            coordinates.len()
+                        )))
+                    }
+                };
+                outline
+                    .as_mut()
+                    .ok_or_else(|| error("vertex outside of `outer loop`".to_string()))?
+                    .push(vertex);
+            }
+            Some("outer") => {
+                if outline.is_some() {
+                    return Err(error("nested `outer loop`".to_string()));
+                }
+                outline = Some(Vec::new());
+            }
+            Some("endloop") => {
+                let points = outline
+                    .take()
+                    .ok_or_else(|| error("`endloop` without `outer loop`".to_string()))?;
+                if points.len() < 3 {
+                    return Err(error(format!(
+                        "facet has {} vertices, expected at least 3",
+                        points.len()
+                    )));
+                }
+                let facet = Polygon::new(points)
+                    .triangulate()
+                    .ok_or_else(|| error("facet crosses itself".to_string()))?;
+                triangles.extend(facet);
+            }
+            Some("solid") | Some("endsolid") | Some("facet") | Some("endfacet") | None => {}
+            Some(keyword) => return Err(error(format!("unexpected keyword `{}`", keyword))),
+        }
+    }
+
+    if outline.is_some() {
+        return Err(StlError::Ascii {
+            line: text.lines().count(),
+            message: "unterminated `outer loop`".to_string(),
+        });
+    }
+
+    Ok(triangles)
+}
@@ -0,0 +1,137 @@
+use super::{TetMesh, TetMeshError, Tokens};
+use crate::point::Point;
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+fn with_suffix(base: &Path, suffix: &str) -> PathBuf {
+    let mut path = base.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+fn in_file(path: &Path, error: TetMeshError) -> TetMeshError {
+    match error {
+        TetMeshError::Parse { line, message } => TetMeshError::Parse {
+            line,
+            message: format!("{}: {}", path.display(), message),
+        },
+        error => error,
+    }
+}
+
+pub fn load<P: AsRef<Path>>(base: P) -> Result<TetMesh, TetMeshError> {
+    let (node_path, ele_path) = (
+        with_suffix(base.as_ref(), ".node"),
+        with_suffix(base.as_ref(), ".ele"),
+    );
+    let (nodes, elements) = (
+        fs::read_to_string(&node_path)?,
+        fs::read_to_string(&ele_path)?,
+    );
+
+    let (points, base) = parse_nodes(&nodes).map_err(|error| in_file(&node_path, error))?;
+    let tetrahedra =
+        parse_elements(&elements, base, points.len()).map_err(|error| in_file(&ele_path, error))?;
+
+    Ok(TetMesh { points, tetrahedra })
+}
+
+pub fn parse_nodes(text: &str) -> Result<(Vec<Point>, usize), TetMeshError> {
+    let mut tokens = Tokens::new(text, Some('#'));
+    let count = tokens.parse::<usize>("node count")?;
+    let dimension = tokens.parse::<usize>("dimension")?;
+    if dimension != 3 {
+        return Err(tokens.error(format!("unsupported dimension {}", dimension)));
+    }
+    tokens.skip_line();
+
+    let mut points = Vec::with_capacity(count);
+    let mut base = 0;
+    for i in 0..count {
+        let index = tokens.parse::<usize>("node index")?;
+        if i == 0 {
+            base = index;
+        }
+        let x = tokens.parse("coordinate")?;
+        let y = tokens.parse("coordinate")?;
+        let z = tokens.parse("coordinate")?;
+        points.push(Point::new(x, y, z));
+        tokens.skip_line();
+    }
+
+    Ok((points, base))
+}
+
+pub fn parse_elements(
+    text: &str,
+    base: usize,
+    point_count: usize,
+) -> Result<Vec<[usize; 4]>, TetMeshError> {
+    let mut tokens = Tokens::new(text, Some('#'));
+    let count = tokens.parse::<usize>("tetrahedron count")?;
+    let nodes_per_tetrahedron = tokens.parse::<usize>("nodes per tetrahedron")?;
+    if nodes_per_tetrahedron != 4 && nodes_per_tetrahedron != 10 {
+        return Err(tokens.error(format!(
+            "unsupported nodes per tetrahedron {}",
+            nodes_per_tetrahedron
+        )));
+    }
+    tokens.skip_line();
+
+    let mut tetrahedra = Vec::with_capacity(count);
+    for _ in 0..count {
+        tokens.parse::<usize>("tetrahedron index")?;
+        let mut tetrahedron = [0; 4];
+        for index in tetrahedron.iter_mut() {
+            *index = tokens.index(base, point_count)?;
+        }
+        tetrahedra.push(tetrahedron);
+        tokens.skip_line();
+    }
+
+    Ok(tetrahedra)
+}
+
+pub fn save<P: AsRef<Path>>(base: P, mesh: &TetMesh) -> io::Result<()> {
+    let base = base.as_ref();
+    write_nodes(
+        BufWriter::new(File::create(with_suffix(base, ".node"))?),
+        mesh,
+    )?;
+    write_elements(
+        BufWriter::new(File::create(with_suffix(base, ".ele"))?),
+        mesh,
+    )?;
+    write_faces(
+        BufWriter::new(File::create(with_suffix(base, ".face"))?),
+        mesh,
+    )
+}
+
+pub fn write_nodes<W: Write>(mut writer: W, mesh: &TetMesh) -> io::Result<()> {
+    writeln!(writer, "{} 3 0 0", mesh.points.len())?;
+    for (i, point) in mesh.points.iter().enumerate() {
+        writeln!(writer, "{} {} {} {}", i, point.x, point.y, point.z)?;
+    }
+    writer.flush()
+}
+
+pub fn write_elements<W: Write>(mut writer: W, mesh: &TetMesh) -> io::Result<()> {
+    writeln!(writer, "{} 4 0", mesh.tetrahedra.len())?;
+    for (i, [a, b, c, d]) in mesh.tetrahedra.iter().enumerate() {
+        writeln!(writer, "{} {} {} {} {}", i, a, b, c, d)?;
+    }
+    writer.flush()
+}
+
+pub fn write_faces<W: Write>(mut writer: W, mesh: &TetMesh) -> io::Result<()> {
+    let boundary = mesh.boundary_faces();
+    writeln!(writer, "{} 1", boundary.len())?;
+    for (i, [a, b, c]) in boundary.iter().enumerate() {
+        writeln!(writer, "{} {} {} {} 1", i, a, b, c)?;
+    }
+    writer.flush()
+}
@@ -0,0 +1,137 @@
+use super::{TetMesh, TetMeshError, Tokens};
+use crate::point::Point;
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+const VTK_TETRA: usize = 10;
+
+pub fn load<P: AsRef<Path>>(path: P) -> Result<TetMesh, TetMeshError> {
+    parse(&fs::read_to_string(path)?)
+}
+
+pub fn parse(text: &str) -> Result<TetMesh, TetMeshError> {
+    if !text.starts_with("# vtk DataFile") {
+        return Err(TetMeshError::Parse {
+            line: 1,
+            message: "missing `# vtk DataFile` header".to_string(),
+        });
+    }
+
+    let mut tokens = Tokens::new(text, None);
+    tokens.tokens.retain(|&(line, _)| line > 2);
+
+    let mut mesh = TetMesh::default();
+    let mut cells = Vec::new();
+    let mut types = Vec::new();
+
+    while let Some(keyword) = tokens.peek() {
+        tokens.next()?;
+        match keyword.to_uppercase().as_str() {
+            "ASCII" => {}
+            "BINARY" => return Err(tokens.error("binary vtk files are not supported".to_string())),
+            "DATASET" => {
+                let dataset = tokens.next()?;
+                if !dataset.eq_ignore_ascii_case("UNSTRUCTURED_GRID") {
+                    return Err(tokens.error(format!("unsupported dataset `{}`", dataset)));
+                }
+            }
+            "POINTS" => {
+                let count = tokens.parse::<usize>("point count")?;
+                tokens.next()?;
+                for _ in 0..count {
+                    let x = tokens.parse("coordinate")?;
+                    let y = tokens.parse("coordinate")?;
+                    let z = tokens.parse("coordinate")?;
+                    mesh.points.push(Point::new(x, y, z));
+                }
+            }
+            "CELLS" => {
+                let count = tokens.parse::<usize>("cell count")?;
+                tokens.next()?;
+                for _ in 0..count {
+                    let size = tokens.parse::<usize>("cell size")?;
+                    let cell = (0..size)
+                        .map(|_| tokens.index(0, mesh.points.len()))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    cells.push(cell);
+                }
+            }
+            "CELL_TYPES" => {
+                let count = tokens.parse::<usize>("cell type count")?;
+                for _ in 0..count {
+                    types.push(tokens.parse::<usize>("cell type")?);
+                }
+            }
+            "CELL_DATA" | "POINT_DATA" => break,
+            _ => return Err(tokens.error(format!("unexpected keyword `{}`", keyword))),
+        }
+    }
+
+    if types.len() != cells.len() {
+        return Err(tokens.error(format!(
+            "{} cells but {} cell types",
+            cells.len(),
+            types.len()
+        )));
+    }
+
+    mesh.tetrahedra = cells
+        .into_iter()
+        .zip(types)
+        .filter(|&(_, cell_type)| cell_type == VTK_TETRA)
+        .filter_map(|(cell, _)| match cell[..] {
+            [a, b, c, d] => Some([a, b, c, d]),
+            _ => None,
+        })
+        .collect();
+
+    Ok(mesh)
+}
+
+pub fn save<P: AsRef<Path>>(path: P, mesh: &TetMesh) -> io::Result<()> {
+    write(BufWriter::new(File::create(path)?), mesh)
+}
+
+pub fn write<W: Write>(mut writer: W, mesh: &TetMesh) -> io::Result<()> {
+    writeln!(writer, "# vtk DataFile Version 3.0")?;
+    writeln!(writer, "medial_axis_3d tetrahedral mesh")?;
+    writeln!(writer, "ASCII")?;
+    writeln!(writer, "DATASET UNSTRUCTURED_GRID")?;
+
+    writeln!(writer, "POINTS {} double", mesh.points.len())?;
+    for point in mesh.points.iter() {
+        writeln!(writer, "{} {} {}", point.x, point.y, point.z)?;
+    }
+
+    writeln!(
+        writer,
+        "CELLS {} {}",
+        mesh.tetrahedra.len(),
+        5 * mesh.tetrahedra.len()
+    )?;
+    for [a, b, c, d] in mesh.tetrahedra.iter() {
+        writeln!(writer, "4 {} {} {} {}", a, b, c, d)?;
+    }
+    writeln!(writer, "CELL_TYPES {}", mesh.tetrahedra.len())?;
+    for _ in mesh.tetrahedra.iter() {
+        writeln!(writer, "{}", VTK_TETRA)?;
+    }
+
+    let tetrahedra = mesh.tetrahedra();
+    writeln!(writer, "CELL_DATA {}", tetrahedra.len())?;
+    writeln!(writer, "SCALARS circumradius double 1")?;
+    writeln!(writer, "LOOKUP_TABLE default")?;
+    for tetra in tetrahedra.iter() {
+        writeln!(writer, "{}", tetra.circumradius.sqrt())?;
+    }
+    writeln!(writer, "SCALARS radius_edge_ratio double 1")?;
+    writeln!(writer, "LOOKUP_TABLE default")?;
+    for tetra in tetrahedra.iter() {
+        writeln!(writer, "{}", tetra.radius_edge_ratio())?;
+    }
+
+    writer.flush()
+}
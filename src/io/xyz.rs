@@ -0,0 +1,194 @@
+use super::Mesh;
+use crate::point::Point;
+use std::{
+    error::Error,
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointCloud {
+    pub points: Vec<Point>,
+    pub normals: Option<Vec<Point>>,
+}
+
+impl From<&Mesh> for PointCloud {
+    fn from(mesh: &Mesh) -> Self {
+        Self {
+            points: mesh.points.clone(),
+            normals: None,
+        }
+    }
+}
+
+impl From<PointCloud> for Mesh {
+    fn from(cloud: PointCloud) -> Self {
+        Self {
+            points: cloud.points,
+            faces: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum XyzError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for XyzError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            XyzError::Io(error) => write!(f, "{}", error),
+            XyzError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl Error for XyzError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            XyzError::Io(error) => Some(error),
+            XyzError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for XyzError {
+    fn from(error: io::Error) -> Self {
+        XyzError::Io(error)
+    }
+}
+
+pub fn load<P: AsRef<Path>>(path: P) -> Result<PointCloud, XyzError> {
+    read(BufReader::new(File::open(path)?))
+}
+
+pub fn read<R: BufRead>(reader: R) -> Result<PointCloud, XyzError> {
+    let mut points = Vec::new();
+    let mut normals = Vec::new();
+    let mut header: Option<Vec<String>> = None;
+    let mut position_columns = [0, 1, 2];
+    let mut normal_columns = None;
+    let mut header_allowed = true;
+
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+        let error = |message: String| XyzError::Parse {
+            line: number + 1,
+            message,
+        };
+
+        let line = line.split('#').next().unwrap_or_default();
+        let line = line.split("//").next().unwrap_or_default();
+        let mut tokens = line
+            .split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+            .filter(|token| !token.is_empty())
+            .peekable();
+        if tokens.peek() == Some(&"v") {
+            tokens.next();
+        }
+        let tokens = tokens.collect::<Vec<_>>();
+        if tokens.is_empty() {
+            continue;
+        }
+
+        let values = tokens
+            .iter()
+            .map(|token| token.parse::<f64>())
+            .collect::<Result<Vec<_>, _>>();
+        let values = match values {
+            Ok(values) => values,
+            Err(_) if header_allowed => {
+                header = Some(tokens.iter().map(|token| token.to_lowercase()).collect());
+                header_allowed = false;
+                continue;
+            }
+            Err(_) => {
+                let token = tokens.iter().find(|token| token.parse::<f64>().is_err());
+                return Err(error(format!("invalid number `{}`", token.unwrap())));
+            }
+        };
+
+        // a header names the position and normal columns, otherwise the position comes first and
+        // the three columns after it are tried as normals
+        if points.is_empty() {
+            let named = |names: [&str; 3]| {
+                let header = header.as_ref()?;
+                let column = |name: &str| header.iter().position(|other| other == name);
+                Some([column(names[0])?, column(names[1])?, column(names[2])?])
+            };
+            position_columns = named(["x", "y", "z"]).unwrap_or([0, 1, 2]);
+            normal_columns = match header {
+                Some(_) => named(["nx", "ny", "nz"]),
+                None if values.len() >= 6 => Some([3, 4, 5]),
+                None => None,
+            };
+        }
+        header_allowed = false;
+
+        let required = position_columns
+            .iter()
+            .chain(normal_columns.iter().flatten())
+            .copied()
+            .max()
+            .unwrap()
+            + 1;
+        if values.len() < required {
+            return Err(error(format!(
+                "found {} values, expected at least {}",
+                values.len(),
+                required
+            )));
+        }
+
+        let [x, y, z] = position_columns;
+        points.push(Point::new(values[x], values[y], values[z]));
+        if let Some([x, y, z]) = normal_columns {
+            normals.push(Point::new(values[x], values[y], values[z]));
+        }
+    }
+
+    // without a header the extra columns could as well be colours or intensities, so they are only
+    // taken for normals when every one of them is a unit vector
+    let is_unit = |normal: &Point| (normal.length() - 1.0).abs() < 1e-2;
+    let normals = normal_columns
+        .map(|_| normals)
+        .filter(|normals| header.is_some() || normals.iter().all(is_unit));
+
+    Ok(PointCloud { points, normals })
+}
+
+pub fn save<P: AsRef<Path>>(path: P, cloud: &PointCloud) -> io::Result<()> {
+    write(BufWriter::new(File::create(path)?), cloud)
+}
+
+pub fn write<W: Write>(mut writer: W, cloud: &PointCloud) -> io::Result<()> {
+    if let Some(normals) = cloud
+        .normals
+        .as_ref()
+        .filter(|normals| normals.len() != cloud.points.len())
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "{} normals for {} points",
+                normals.len(),
+                cloud.points.len()
+            ),
+        ));
+    }
+    if cloud.normals.is_some() {
+        writeln!(writer, "x y z nx ny nz")?;
+    }
+    for (i, Point { x, y, z }) in cloud.points.iter().enumerate() {
+        match cloud.normals.as_ref().map(|normals| normals[i]) {
+            Some(n) => writeln!(writer, "{} {} {} {} {} {}", x, y, z, n.x, n.y, n.z)?,
+            None => writeln!(writer, "{} {} {}", x, y, z)?,
+        }
+    }
+    writer.flush()
+}
@@ -1,22 +1,157 @@
+pub mod alpha;
+pub mod constrained;
 pub mod edge;
 pub mod face;
+pub mod hull;
+pub mod io;
 pub mod point;
+pub mod polygon;
+pub mod quality;
+pub mod reconstruction;
+pub mod refine;
+pub mod regular;
+pub mod repair;
+pub mod sampling;
+pub mod scalar;
+pub mod skeleton;
+pub mod skinning;
+pub mod spheres;
 pub mod tetrahedron;
+#[cfg(feature = "viewer")]
+pub mod viewer;
+pub mod voronoi;
 
 use edge::Edge;
 use face::Face;
-use nalgebra::Vector3;
 use point::Point;
-use std::collections::{HashMap, HashSet};
+use scalar::Scalar;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
 use tetrahedron::Tetrahedron;
 
-pub fn almost_equal(a: f64, b: f64) -> bool {
-    (a - b).abs() <= f64::EPSILON
+pub fn almost_equal<T: Scalar>(a: T, b: T) -> bool {
+    a == b || (a - b).abs() <= T::epsilon()
 }
 
-pub fn delaunay(points: &[Point]) -> Vec<Tetrahedron> {
-    let mut tetrahedrons = Vec::new();
+// whether two points lie on the same side of a face, where points too close to its plane for the
+// rounded orientation to be trusted count as on it
+fn side<T: Scalar>(face: &Face<T>, a: &Point<T>, b: &Point<T>) -> Option<Ordering> {
+    let orient = |point: &Point<T>| {
+        let (u, v, w) = (face.p2 - face.p1, face.p3 - face.p1, *point - face.p1);
+        let volume = u.dot(&v.cross(&w));
+        if volume.abs() <= T::from_f64(1e-12) * (u.norm() * v.norm() * w.norm()).sqrt() {
+            T::from_f64(0.0)
+        } else {
+            volume
+        }
+    };
+    (orient(a) * orient(b)).partial_cmp(&T::from_f64(0.0))
+}
+
+fn encloses<T: Scalar>(tetra: &Tetrahedron<T>, point: &Point<T>) -> bool {
+    let opposite = [tetra.p4, tetra.p3, tetra.p2, tetra.p1];
+    tetra
+        .faces()
+        .iter()
+        .zip(opposite.iter())
+        .all(|(face, opposite)| side(face, opposite, point) != Some(Ordering::Less))
+}
+
+fn boundary<T: Scalar>(
+    tetrahedrons: &[Tetrahedron<T>],
+    cavity: &HashSet<usize>,
+) -> HashMap<Face<T>, (usize, Point<T>)> {
+    let mut faces = HashMap::new();
+    for &i in cavity.iter() {
+        let tetra = &tetrahedrons[i];
+        let opposite = [tetra.p4, tetra.p3, tetra.p2, tetra.p1];
+        for (&face, &opposite) in tetra.faces().iter().zip(opposite.iter()) {
+            if faces.remove(&face).is_none() {
+                faces.insert(face, (i, opposite));
+            }
+        }
+    }
+    faces
+}
+
+// replaces the tetrahedra in conflict with the point by a star around it, returning false when no
+// tetrahedron enclosing the point is in conflict or the point is already a vertex
+pub(crate) fn insert<T: Scalar>(
+    tetrahedrons: &mut Vec<Tetrahedron<T>>,
+    point: &Point<T>,
+    conflict: impl Fn(&Tetrahedron<T>) -> bool,
+) -> bool {
+    let bad = (0..tetrahedrons.len())
+        .filter(|&i| conflict(&tetrahedrons[i]))
+        .collect::<HashSet<_>>();
+    let mut seeds = bad
+        .iter()
+        .copied()
+        .filter(|&i| encloses(&tetrahedrons[i], point))
+        .collect::<HashSet<_>>();
+    if seeds.is_empty() || seeds.iter().any(|&i| tetrahedrons[i].has_point(point)) {
+        return false;
+    }
+
+    // the cavity is kept connected and star-shaped from the point, otherwise rounding on
+    // nearly cospherical points leaves overlapping tetrahedra
+    let mut cavity = bad;
+    loop {
+        let mut neighbours = HashMap::<Face<T>, Vec<usize>>::new();
+        for &i in cavity.iter() {
+            for &face in tetrahedrons[i].faces().iter() {
+                neighbours.entry(face).or_default().push(i);
+            }
+        }
+        let mut reached = HashSet::new();
+        let mut stack = seeds.iter().copied().collect::<Vec<_>>();
+        while let Some(i) = stack.pop() {
+            if reached.insert(i) {
+                for face in tetrahedrons[i].faces().iter() {
+                    stack.extend(neighbours[face].iter().copied());
+                }
+            }
+        }
+        cavity = reached;
+
+        let hidden = boundary(tetrahedrons, &cavity)
+            .into_iter()
+            .filter(|(face, (_, opposite))| side(face, opposite, point) != Some(Ordering::Greater))
+            .collect::<Vec<_>>();
+        let mut changed = false;
+        for (face, (i, _)) in hidden {
+            if !seeds.contains(&i) {
+                changed |= cavity.remove(&i);
+            } else if let Some(j) = (0..tetrahedrons.len())
+                .find(|&j| !cavity.contains(&j) && tetrahedrons[j].faces().contains(&face))
+            {
+                seeds.insert(j);
+                changed |= cavity.insert(j);
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
 
+    let hole = boundary(tetrahedrons, &cavity);
+    let mut index = 0;
+    tetrahedrons.retain(|_| {
+        index += 1;
+        !cavity.contains(&(index - 1))
+    });
+    for face in hole.keys() {
+        let tetra = Tetrahedron::new(face.p1, face.p2, face.p3, *point);
+        if tetra.circumcenter.is_finite() {
+            tetrahedrons.push(tetra);
+        }
+    }
+    true
+}
+
+pub(crate) fn super_tetrahedron<T: Scalar>(points: &[Point<T>]) -> Tetrahedron<T> {
     let Point {
         x: mut min_x,
         y: mut min_y,
@@ -45,69 +180,85 @@ pub fn delaunay(points: &[Point]) -> Vec<Tetrahedron> {
         }
     }
 
-    let d_max = *[max_x - min_x, max_y - min_y, max_z - min_z]
+    let d_max = [max_x - min_x, max_y - min_y, max_z - min_z]
         .iter()
-        .max_by(|a, b| a.partial_cmp(b).unwrap())
-        .unwrap();
-
-    let (mid_x, mid_z) = ((min_x + max_x) / 2.0, (min_z + max_z) / 2.0);
+        .copied()
+        .fold(max_x - min_x, |d_max, d| if d > d_max { d } else { d_max });
+    let (two, twenty) = (T::from_f64(2.0), T::from_f64(20.0));
 
-    let super_p1 = Point::new(min_x - 20.0 * d_max, min_y - d_max, min_z - 20.0 * d_max);
-    let super_p2 = Point::new(max_x + 20.0 * d_max, min_y - d_max, min_z - 20.0 * d_max);
-    let super_p3 = Point::new(mid_x, min_y - d_max, max_z + 20.0 * d_max);
-    let super_p4 = Point::new(mid_x, max_y + 20.0 * d_max, mid_z);
+    let (mid_x, mid_z) = ((min_x + max_x) / two, (min_z + max_z) / two);
 
-    tetrahedrons.push((
-        Tetrahedron::new(super_p1, super_p2, super_p3, super_p4),
-        false,
-    ));
+    // snapped to plain values so the super vertices compare equal to themselves for interval scalars
+    let snap = |x: T, y: T, z: T| {
+        Point::new(
+            T::from_f64(x.to_f64()),
+            T::from_f64(y.to_f64()),
+            T::from_f64(z.to_f64()),
+        )
+    };
+    let super_p1 = snap(
+        min_x - twenty * d_max,
+        min_y - d_max,
+        min_z - twenty * d_max,
+    );
+    let super_p2 = snap(
+        max_x + twenty * d_max,
+        min_y - d_max,
+        min_z - twenty * d_max,
+    );
+    let super_p3 = snap(mid_x, min_y - d_max, max_z + twenty * d_max);
+    let super_p4 = snap(mid_x, max_y + twenty * d_max, mid_z);
 
-    let mut hole = HashSet::new();
-    for point in points.iter() {
-        for (tetra, bad) in tetrahedrons.iter_mut() {
-            if tetra.has_point_circumcircle(point) {
-                *bad = true;
-                let faces = [
-                    Face::new(tetra.p1, tetra.p2, tetra.p3),
-                    Face::new(tetra.p1, tetra.p2, tetra.p4),
-                    Face::new(tetra.p1, tetra.p3, tetra.p4),
-                    Face::new(tetra.p2, tetra.p3, tetra.p4),
-                ];
-                for &face in faces.iter() {
-                    if !hole.insert(face) {
-                        hole.remove(&face);
-                    }
-                }
-            }
-        }
-
-        tetrahedrons.retain(|&(_, bad)| !bad);
+    Tetrahedron::new(super_p1, super_p2, super_p3, super_p4)
+}
 
-        for face in hole.iter() {
-            tetrahedrons.push((Tetrahedron::new(face.p1, face.p2, face.p3, *point), false));
-        }
-        tetrahedrons.retain(|(tetra, _)| tetra.circumcenter.is_normal());
-        hole.clear();
+// falls back to the tetrahedra enclosing the point when rounding leaves none of them in conflict
+// with it
+pub(crate) fn insert_delaunay<T: Scalar>(tetrahedrons: &mut Vec<Tetrahedron<T>>, point: &Point<T>) {
+    if !insert(tetrahedrons, point, |tetra| {
+        tetra.has_point_circumcircle(point)
+    }) {
+        insert(tetrahedrons, point, |tetra| {
+            tetra.has_point_circumcircle(point) || encloses(tetra, point)
+        });
     }
+}
 
+pub(crate) fn without_super_tetrahedron<T: Scalar>(
+    tetrahedrons: &[Tetrahedron<T>],
+    super_tetra: &Tetrahedron<T>,
+) -> Vec<Tetrahedron<T>> {
     tetrahedrons
-        .into_iter()
-        .filter_map(|(tetra, _)| {
-            if [super_p1, super_p2, super_p3, super_p4]
-                .iter()
-                .all(|p| !tetra.has_point(p))
-            {
-                Some(tetra)
-            } else {
-                None
-            }
+        .iter()
+        .filter(|tetra| {
+            [
+                super_tetra.p1,
+                super_tetra.p2,
+                super_tetra.p3,
+                super_tetra.p4,
+            ]
+            .iter()
+            .all(|p| !tetra.has_point(p))
         })
+        .copied()
         .collect()
 }
 
-pub fn face_adjacency(
-    tetrahedrons: &[Tetrahedron],
-) -> HashMap<Face, (Tetrahedron, Option<Tetrahedron>)> {
+pub fn delaunay<T: Scalar>(points: &[Point<T>]) -> Vec<Tetrahedron<T>> {
+    let super_tetra = super_tetrahedron(points);
+    let mut tetrahedrons = vec![super_tetra];
+
+    for point in points.iter() {
+        insert_delaunay(&mut tetrahedrons, point);
+    }
+
+    without_super_tetrahedron(&tetrahedrons, &super_tetra)
+}
+
+// the one or two tetrahedra on either side of every face
+pub type FaceAdjacency<T = f64> = HashMap<Face<T>, (Tetrahedron<T>, Option<Tetrahedron<T>>)>;
+
+pub fn face_adjacency<T: Scalar>(tetrahedrons: &[Tetrahedron<T>]) -> FaceAdjacency<T> {
     let mut faces = HashMap::new();
 
     for &tetra in tetrahedrons {
@@ -133,29 +284,30 @@ pub fn face_adjacency(
     faces
 }
 
-pub fn medial_axis(tetrahedrons: &[Tetrahedron]) -> Vec<Edge> {
+pub fn medial_axis<T: Scalar>(tetrahedrons: &[Tetrahedron<T>]) -> Vec<Edge<T>> {
     face_adjacency(tetrahedrons)
         .into_iter()
         .filter_map(|(_, (t1, t2))| t2.map(|t2| Edge::new(t1.circumcenter, t2.circumcenter)))
         .collect()
 }
 
-pub fn point_inside_shape(point: &Point, shape: &[Face]) -> bool {
-    fn orient_3d(points: [&Point; 4]) -> f64 {
-        let a = Vector3::new(points[0].x, points[0].y, points[0].z);
-        let b = Vector3::new(points[1].x, points[1].y, points[1].z);
-        let c = Vector3::new(points[2].x, points[2].y, points[2].z);
-        let d = Vector3::new(points[3].x, points[3].y, points[3].z);
-        Vector3::dot(&Vector3::cross(&(b - a), &(c - a)), &(d - a)).signum()
+pub fn point_inside_shape<T: Scalar>(point: &Point<T>, shape: &[Face<T>]) -> bool {
+    fn orient_3d<T: Scalar>([a, b, c, d]: [&Point<T>; 4]) -> T {
+        let volume = (*b - *a).cross(&(*c - *a)).dot(&(*d - *a));
+        T::from_f64(match volume.partial_cmp(&T::from_f64(0.0)) {
+            Some(Ordering::Greater) => 1.0,
+            Some(Ordering::Less) => -1.0,
+            _ => 0.0,
+        })
     }
 
-    fn intersect(
-        Edge { p1: q1, p2: q2 }: &Edge,
+    fn intersect<T: Scalar>(
+        Edge { p1: q1, p2: q2 }: &Edge<T>,
         Face {
             p1: t1,
             p2: t2,
             p3: t3,
-        }: &Face,
+        }: &Face<T>,
     ) -> bool {
         let s1 = orient_3d([q1, t1, t2, t3]);
         let s2 = orient_3d([q2, t1, t2, t3]);
@@ -168,7 +320,8 @@ pub fn point_inside_shape(point: &Point, shape: &[Face]) -> bool {
         almost_equal(s3, s4) && almost_equal(s4, s5)
     }
 
-    let segment = Edge::new(*point, Point::new(point.x, point.y, point.z + 1e30));
+    let far = Point::new(point.x, point.y, point.z + T::from_f64(1e30));
+    let segment = Edge::new(*point, far);
     let mut inside = false;
 
     for face in shape.iter() {
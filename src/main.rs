@@ -1,235 +1,594 @@
-use medial_axis_3d::{delaunay, face::Face, medial_axis, point::Point, point_inside_shape};
-use raylib::prelude::{
-    consts::CameraMode, get_random_value, rstr, Camera3D, Color, KeyboardKey, RaylibDraw,
-    RaylibDraw3D, RaylibDrawGui, RaylibMode3DExt, Rectangle, Vector3,
-};
-use std::{
-    io::{BufRead, BufReader},
-    time::UNIX_EPOCH,
+use medial_axis_3d::{
+    alpha::AlphaComplex,
+    constrained::{constrained_delaunay, ConstrainedError},
+    delaunay,
+    face::Face,
+    io::{self, gltf, load_mesh, load_tet_mesh, ply::Ply, save_mesh, save_tet_mesh, Mesh, TetMesh},
+    point::Point,
+    quality::{quality_histogram, Metric},
+    reconstruction::{hausdorff_distance, union_of_balls},
+    refine::{refine, RefineOptions},
+    repair,
+    sampling::{sample_surface, Sampling},
+    skeleton::Skeleton,
+    skinning::skinning_weights,
+    spheres::{medial_balls, select_spheres, Sphere},
+    tetrahedron::Tetrahedron,
 };
+use std::{path::Path, process::exit};
 
-fn main() {
-    let mut args = std::env::args().skip(1);
-    let file_name = args.next().expect("no file given");
-    let input = BufReader::new(std::fs::File::open(&file_name).unwrap());
-
-    let mut points = Vec::new();
-    let mut faces = Vec::new();
-
-    for line in input.lines().map(Result::unwrap) {
-        let mut tokens = line.trim().split_whitespace();
-        match tokens.next().unwrap() {
-            "v" => {
-                let (x, y, z) = (
-                    tokens.next().unwrap().parse().unwrap(),
-                    tokens.next().unwrap().parse().unwrap(),
-                    tokens.next().unwrap().parse().unwrap(),
-                );
-                points.push(Point::new(x, y, z));
+const USAGE: &str = "usage: medial_axis_3d <command> [options] <input> [output]
+
+commands:
+  triangulate <input> <output>  write the interior tetrahedralization (.vtk, .mesh, .node/.ele)
+  skeleton <input> <output>     write the medial axis (.obj, .json, .graphml, .dot, .ply, .gltf, .glb)
+  spheres <input> <output>      write medial balls covering the surface as a collision proxy (.json, or
+                                binary for any other extension)
+  validate <input>              report surface defects, failing if any are found
+  convert <input> <output>      convert between surface or tetrahedral mesh formats
+  view <input>                  open the interactive viewer (needs the viewer feature)
+
+inputs are surfaces or point clouds to tetrahedralize, or tetrahedral meshes (.vtk, .mesh,
+.node/.ele) to use as they are
+
+options:
+  --repair          repair the surface before triangulating
+  --uniform=N       sample N points uniformly over the surface
+  --poisson=R       sample the surface with poisson disk radius R
+  --adaptive=E      sample the surface adaptively with epsilon E
+  --max-steiner=N   limit the steiner points used to recover or refine the surface
+  --refine=B        refine until every tetrahedron has a radius-edge ratio below B, except in
+                    corners of the surface sharper than 60 degrees
+  --min-dihedral=A  while refining, also split tetrahedra with a dihedral angle below A degrees
+  --alpha=R         for point clouds, keep tetrahedra with a circumradius up to R (defaults to the
+                    smallest R that covers every point)
+  --prune=L         remove skeleton branches shorter than L
+  --fidelity=S      rebuild the surface from the skeleton's balls on a grid of spacing S and print its
+                    hausdorff distance to the input
+  --weights=F       write the skinning weights of the surface vertices to the csv file F, as they are
+                    written into .gltf and .glb skeletons
+  --count=N         keep at most N spheres (defaults to 64)
+  --max-error=E     count a surface point as covered by a sphere within distance E of it (defaults
+                    to a hundredth of the bounding box diagonal)
+  --quality         print tetrahedron quality histograms after triangulating";
+
+struct Options {
+    repair: bool,
+    sampling: Sampling,
+    max_steiner_points: Option<usize>,
+    refine: Option<f64>,
+    min_dihedral_angle: Option<f64>,
+    alpha: Option<f64>,
+    prune: f64,
+    fidelity: Option<f64>,
+    weights: Option<String>,
+    sphere_count: usize,
+    max_error: Option<f64>,
+    quality: bool,
+}
+
+// the options each command makes use of, any other is rejected rather than silently ignored. An
+// unknown command accepts them all so that it is reported instead.
+fn accepted_options(command: &str) -> Vec<&'static str> {
+    let extra: &[&str] = match command {
+        "triangulate" => &["--quality"],
+        "skeleton" => &["--prune", "--fidelity", "--weights"],
+        "spheres" => &["--count", "--max-error"],
+        "view" => &[],
+        "validate" | "convert" => return Vec::new(),
+        _ => &[
+            "--quality",
+            "--prune",
+            "--fidelity",
+            "--weights",
+            "--count",
+            "--max-error",
+        ],
+    };
+    [
+        "--repair",
+        "--uniform",
+        "--poisson",
+        "--adaptive",
+        "--max-steiner",
+        "--refine",
+        "--min-dihedral",
+        "--alpha",
+    ]
+    .iter()
+    .chain(extra.iter())
+    .copied()
+    .collect()
+}
+
+impl Options {
+    fn parse(flags: &[String], command: &str) -> Result<Self, String> {
+        let accepted = accepted_options(command);
+        let mut options = Self {
+            repair: false,
+            sampling: Sampling::Vertices,
+            max_steiner_points: None,
+            refine: None,
+            min_dihedral_angle: None,
+            alpha: None,
+            prune: 0.0,
+            fidelity: None,
+            weights: None,
+            sphere_count: 64,
+            max_error: None,
+            quality: false,
+        };
+
+        for flag in flags {
+            let (name, value) = match flag.find('=') {
+                Some(index) => (&flag[..index], Some(&flag[index + 1..])),
+                None => (flag.as_str(), None),
+            };
+            let value = |what: &str| {
+                value.ok_or_else(|| format!("{} expects a value, e.g. {}=<{}>", name, name, what))
+            };
+            let invalid = |what: &str| format!("invalid {} for {}", what, name);
+            if ["--uniform", "--poisson", "--adaptive"].contains(&name)
+                && !matches!(options.sampling, Sampling::Vertices)
+            {
+                return Err("only one of --uniform, --poisson and --adaptive can be given".into());
+            }
+            match name {
+                "--repair" => options.repair = true,
+                "--quality" => options.quality = true,
+                "--uniform" => {
+                    options.sampling = Sampling::Uniform {
+                        count: value("count")?.parse().map_err(|_| invalid("count"))?,
+                    }
+                }
+                "--poisson" => {
+                    options.sampling = Sampling::PoissonDisk {
+                        radius: value("radius")?
+                            .parse()
+                            .ok()
+                            .filter(|&radius: &f64| radius > 0.0 && radius.is_finite())
+                            .ok_or_else(|| invalid("radius"))?,
+                    }
+                }
+                "--adaptive" => {
+                    options.sampling = Sampling::Adaptive {
+                        epsilon: value("epsilon")?
+                            .parse()
+                            .ok()
+                            .filter(|&epsilon: &f64| epsilon > 0.0 && epsilon.is_finite())
+                            .ok_or_else(|| invalid("epsilon"))?,
+                    }
+                }
+                "--max-steiner" => {
+                    options.max_steiner_points =
+                        Some(value("count")?.parse().map_err(|_| invalid("count"))?)
+                }
+                "--refine" => {
+                    options.refine = Some(value("bound")?.parse().map_err(|_| invalid("bound"))?)
+                }
+                "--min-dihedral" => {
+                    options.min_dihedral_angle =
+                        Some(value("angle")?.parse().map_err(|_| invalid("angle"))?)
+                }
+                "--alpha" => {
+                    options.alpha = Some(value("radius")?.parse().map_err(|_| invalid("radius"))?)
+                }
+                "--prune" => {
+                    options.prune = value("length")?.parse().map_err(|_| invalid("length"))?
+                }
+                "--fidelity" => {
+                    options.fidelity =
+                        Some(value("spacing")?.parse().map_err(|_| invalid("spacing"))?)
+                }
+                "--weights" => options.weights = Some(value("file")?.to_string()),
+                "--count" => {
+                    options.sphere_count = value("count")?.parse().map_err(|_| invalid("count"))?
+                }
+                "--max-error" => {
+                    options.max_error = Some(
+                        value("distance")?
+                            .parse()
+                            .ok()
+                            .filter(|&distance: &f64| distance >= 0.0 && distance.is_finite())
+                            .ok_or_else(|| invalid("distance"))?,
+                    )
+                }
+                _ => return Err(format!("unknown option {}", flag)),
             }
-            "f" => {
-                let (p1, p2, p3) = (
-                    tokens.next().unwrap().parse::<usize>().unwrap(),
-                    tokens.next().unwrap().parse::<usize>().unwrap(),
-                    tokens.next().unwrap().parse::<usize>().unwrap(),
-                );
-                faces.push(Face::new(points[p1 - 1], points[p2 - 1], points[p3 - 1]));
+            if !accepted.contains(&name) {
+                return Err(format!("{} does not apply to {}", name, command));
             }
-            _ => continue,
-        };
+        }
+
+        if options.min_dihedral_angle.is_some() && options.refine.is_none() {
+            return Err("--min-dihedral needs --refine".into());
+        }
+        Ok(options)
     }
+}
 
-    let start = std::time::Instant::now();
-    let mut tetrahedrons = delaunay(&points);
-    println!("{}", start.elapsed().as_micros());
+fn main() {
+    let (flags, args): (Vec<_>, Vec<_>) = std::env::args()
+        .skip(1)
+        .partition(|arg| arg.starts_with("--"));
 
-    tetrahedrons.retain(|tetra| point_inside_shape(&tetra.centroid(), &faces));
-    let medial_axis = medial_axis(&tetrahedrons);
+    if flags.iter().any(|flag| flag == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
 
-    let tetrahedrons_draw = tetrahedrons
-        .iter()
-        .map(|t| {
-            (
-                t,
-                Color::color_from_hsv(get_random_value::<i32>(0, 360) as f32, 0.5, 0.8),
-            )
-        })
-        .collect::<Vec<_>>();
+    let command = args.first().map_or("", String::as_str);
+    let result = Options::parse(&flags, command).and_then(|options| {
+        match (args.first().map(String::as_str), &args[args.len().min(1)..]) {
+            (Some("triangulate"), [input, output]) => triangulate(input, output, &options),
+            (Some("skeleton"), [input, output]) => skeleton(input, output, &options),
+            (Some("spheres"), [input, output]) => spheres(input, output, &options),
+            (Some("validate"), [input]) => validate(input),
+            (Some("convert"), [input, output]) => convert(input, output),
+            (Some("view"), [input]) => view(input, &options),
+            (Some(command), _)
+                if [
+                    "triangulate",
+                    "skeleton",
+                    "spheres",
+                    "validate",
+                    "convert",
+                    "view",
+                ]
+                .contains(&command) =>
+            {
+                Err(format!(
+                    "wrong number of arguments for {}\n\n{}",
+                    command, USAGE
+                ))
+            }
+            (Some(command), _) => Err(format!("unknown command {}\n\n{}", command, USAGE)),
+            (None, _) => Err(USAGE.to_string()),
+        }
+    });
+
+    if let Err(error) = result {
+        eprintln!("error: {}", error);
+        exit(1);
+    }
+}
 
-    let medial_axis_draw = medial_axis
+fn load(input: &str, options: &Options) -> Result<(Vec<Point>, Vec<Face>), String> {
+    let Mesh {
+        mut points,
+        mut faces,
+    } = load_mesh(input).map_err(|error| format!("{}: {}", input, error))?;
+
+    // surfaces and point clouds are tetrahedralized differently, and each ignores the other's options
+    let ignored = if faces.is_empty() {
+        [
+            ("--repair", options.repair),
+            (
+                "--uniform, --poisson or --adaptive",
+                !matches!(options.sampling, Sampling::Vertices),
+            ),
+            ("--max-steiner", options.max_steiner_points.is_some()),
+            ("--refine", options.refine.is_some()),
+            ("--fidelity", options.fidelity.is_some()),
+            ("--weights", options.weights.is_some()),
+        ]
         .iter()
-        .map(|edge| (Vector3::from(edge.p1), Vector3::from(edge.p2)))
-        .collect::<Vec<_>>();
+        .find(|(_, given)| *given)
+        .map(|(name, _)| format!("{}: {} needs a surface, not a point cloud", input, name))
+    } else {
+        options
+            .alpha
+            .map(|_| format!("{}: --alpha only applies to point clouds", input))
+    };
+    if let Some(error) = ignored {
+        return Err(error);
+    }
 
-    let (mut rl_handle, rl_thread) = raylib::init().size(1000, 1000).title("skeleton 3d").build();
-    rl_handle.set_target_fps(60);
+    if options.repair {
+        faces = repair::repair(&faces, &repair::RepairOptions::default());
+        points = repair::face_vertices(&faces);
+    }
+    if points.len() < 4 {
+        return Err(format!("{}: at least 4 points are needed", input));
+    }
 
-    let mut camera = Camera3D::perspective(
-        Vector3::new(50.0, 50.0, 50.0),
-        Vector3::zero(),
-        Vector3::up(),
-        45.0,
-    );
-    rl_handle.set_camera_mode(camera, CameraMode::CAMERA_FREE);
+    Ok(sample_surface(
+        &points,
+        &faces,
+        &options.sampling,
+        &mut rand::thread_rng(),
+    ))
+}
 
-    let mut show_ui = true;
+fn is_tet_mesh(path: &str) -> bool {
+    matches!(
+        extension(path).as_deref(),
+        Some("vtk") | Some("mesh") | Some("node") | Some("ele") | Some("face")
+    )
+}
 
-    let mut tetra_iter = (0..tetrahedrons.len()).cycle();
-    let mut tetra_index = tetra_iter.next().unwrap();
+// the points and boundary faces of an input along with its tetrahedra
+type Tetrahedralized = (Vec<Point>, Vec<Face>, Vec<Tetrahedron>);
 
-    let mut show_delaunay = false;
-    let mut show_skeleton = false;
-    let mut show_skeleton_balls = false;
-    let mut show_outline = true;
-    let mut show_vertices = true;
-    let mut show_spheres = false;
-    let mut show_grid = true;
+// tetrahedral meshes are used as they are, anything else is loaded as a surface or point cloud and
+// tetrahedralized
+fn load_tetrahedrons(input: &str, options: &Options) -> Result<Tetrahedralized, String> {
+    if !is_tet_mesh(input) {
+        let (points, faces) = load(input, options)?;
+        let tetrahedrons = tetrahedralize(&points, &faces, options)
+            .map_err(|error| format!("{}: {}", input, error))?;
+        return Ok((points, faces, tetrahedrons));
+    }
 
-    while !rl_handle.window_should_close() {
-        if rl_handle.is_key_pressed(KeyboardKey::KEY_U) {
-            show_ui = !show_ui;
-        }
+    if let Some((name, _)) = [
+        ("--repair", options.repair),
+        (
+            "--uniform, --poisson or --adaptive",
+            !matches!(options.sampling, Sampling::Vertices),
+        ),
+        ("--max-steiner", options.max_steiner_points.is_some()),
+        ("--refine", options.refine.is_some()),
+        ("--alpha", options.alpha.is_some()),
+    ]
+    .iter()
+    .find(|(_, given)| *given)
+    {
+        return Err(format!(
+            "{}: {} does not apply to a tetrahedral mesh",
+            input, name
+        ));
+    }
+    let mesh = load_tet_mesh(input).map_err(|error| format!("{}: {}", input, error))?;
+    let faces = mesh
+        .boundary_faces()
+        .iter()
+        .map(|&[a, b, c]| Face::new(mesh.points[a], mesh.points[b], mesh.points[c]))
+        .collect();
+    Ok((mesh.points.clone(), faces, mesh.tetrahedra()))
+}
 
-        if rl_handle.is_key_pressed(KeyboardKey::KEY_S) {
-            rl_handle.take_screenshot(
-                &rl_thread,
-                format!(
-                    "{}.png",
-                    std::time::SystemTime::now()
-                        .duration_since(UNIX_EPOCH)
-                        .unwrap()
-                        .as_secs()
-                )
-                .as_str(),
+fn tetrahedralize(
+    points: &[Point],
+    faces: &[Face],
+    options: &Options,
+) -> Result<Vec<Tetrahedron>, ConstrainedError> {
+    Ok(if faces.is_empty() {
+        let complex = AlphaComplex::new(&delaunay(points));
+        let alpha = options
+            .alpha
+            .map_or_else(|| complex.covering_alpha(), |radius| radius * radius);
+        complex.shape(alpha).tetrahedrons
+    } else if let Some(max_radius_edge_ratio) = options.refine {
+        let refine_options = RefineOptions {
+            max_radius_edge_ratio,
+            min_dihedral_angle: options.min_dihedral_angle,
+            max_steiner_points: options
+                .max_steiner_points
+                .unwrap_or(RefineOptions::default().max_steiner_points),
+        };
+        let refinement = refine(points, faces, &refine_options)?;
+        if refinement.budget_exhausted {
+            eprintln!(
+                "warning: the {} steiner points ran out before every tetrahedron met the bounds",
+                refine_options.max_steiner_points
             );
         }
+        refinement.tetrahedrons
+    } else {
+        let max_steiner_points = options.max_steiner_points.unwrap_or(10 * points.len());
+        constrained_delaunay(points, faces, max_steiner_points)?.interior()
+    })
+}
 
-        rl_handle.update_camera(&mut camera);
+fn extension(path: &str) -> Option<String> {
+    Path::new(path)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_lowercase)
+}
 
-        let mut draw_handle = rl_handle.begin_drawing(&rl_thread);
-        draw_handle.clear_background(Color::WHITE);
+fn triangulate(input: &str, output: &str, options: &Options) -> Result<(), String> {
+    let (_, _, tetrahedrons) = load_tetrahedrons(input, options)?;
+    save_tet_mesh(output, &TetMesh::from_tetrahedra(&tetrahedrons))
+        .map_err(|error| format!("{}: {}", output, error))?;
+    println!("{} tetrahedrons written to {}", tetrahedrons.len(), output);
 
+    if options.quality {
+        for &metric in [
+            Metric::RadiusEdgeRatio,
+            Metric::AspectRatio,
+            Metric::MinDihedralAngle,
+            Metric::MaxDihedralAngle,
+        ]
+        .iter()
         {
-            let mut draw_handle = draw_handle.begin_mode3D(camera);
+            println!("\n{}", metric);
+            println!("{}", quality_histogram(&tetrahedrons, metric, 10));
+        }
+    }
+    Ok(())
+}
 
-            if show_delaunay {
-                for &(tetra, color) in tetrahedrons_draw.iter() {
-                    tetra.draw(&mut draw_handle, color, false);
-                }
-            }
+fn skeleton(input: &str, output: &str, options: &Options) -> Result<(), String> {
+    let (_, faces, tetrahedrons) = load_tetrahedrons(input, options)?;
+    let skeleton = Skeleton::new(&tetrahedrons).pruned(options.prune);
 
-            if show_skeleton {
-                for (v1, v2) in medial_axis_draw.iter() {
-                    draw_handle.draw_line_3D(v1, v2, Color::PURPLE);
-                    if show_skeleton_balls {
-                        draw_handle.draw_sphere_ex(v1, 0.2, 4, 6, Color::PURPLE);
-                        draw_handle.draw_sphere_ex(v2, 0.2, 4, 6, Color::PURPLE);
-                    }
-                }
-            }
+    let is_gltf = matches!(extension(output).as_deref(), Some("gltf") | Some("glb"));
+    let vertices = repair::face_vertices(&faces);
+    let (armature, skin) = if is_gltf || options.weights.is_some() {
+        let armature = skeleton.armature();
+        let skin = skinning_weights(&vertices, &faces, &armature);
+        (armature, skin)
+    } else {
+        Default::default()
+    };
 
-            if show_outline {
-                for face in faces.iter() {
-                    draw_handle.draw_line_3D(
-                        Vector3::from(face.p1),
-                        Vector3::from(face.p2),
-                        Color::BLACK,
-                    );
-                    draw_handle.draw_line_3D(
-                        Vector3::from(face.p2),
-                        Vector3::from(face.p3),
-                        Color::BLACK,
-                    );
-                    draw_handle.draw_line_3D(
-                        Vector3::from(face.p3),
-                        Vector3::from(face.p1),
-                        Color::BLACK,
-                    );
-                }
-            }
+    let result = match extension(output).as_deref() {
+        Some("gltf") | Some("glb") => {
+            let document = gltf::Document {
+                faces: &faces,
+                skeleton: Some(&skeleton),
+                armature: Some(&armature),
+                skin: Some(&skin),
+            };
+            gltf::save(output, &document)
+        }
+        Some("ply") => io::ply::save(
+            output,
+            &Ply::from(&skeleton),
+            io::ply::Format::BinaryLittleEndian,
+        ),
+        _ => io::skeleton::save(output, &skeleton),
+    };
+    result.map_err(|error| format!("{}: {}", output, error))?;
+    println!(
+        "{} nodes and {} edges written to {}",
+        skeleton.nodes.len(),
+        skeleton.edges.len(),
+        output
+    );
 
-            if show_vertices {
-                for point in points.iter() {
-                    draw_handle.draw_sphere_ex(
-                        Vector3::from(*point),
-                        0.2,
-                        4,
-                        6,
-                        if !show_spheres {
-                            Color::BLACK
-                        } else {
-                            let tetra = &tetrahedrons[tetra_index];
-                            if tetra.has_point(point) {
-                                Color::BLUE
-                            } else if tetra.has_point_circumcircle(point) {
-                                Color::RED
-                            } else {
-                                Color::GREEN
-                            }
-                        },
-                    );
-                }
-            }
+    if let Some(path) = &options.weights {
+        io::skin::save(path, &vertices, &skin).map_err(|error| format!("{}: {}", path, error))?;
+        println!(
+            "weights of {} vertices over {} joints written to {}",
+            vertices.len(),
+            armature.joints.len(),
+            path
+        );
+    }
 
-            if show_spheres {
-                let tetra = &tetrahedrons[tetra_index];
-                tetra.draw(&mut draw_handle, Color::BLUE, true);
-                draw_handle.draw_sphere(
-                    Vector3::from(tetra.circumcenter),
-                    tetra.circumradius.sqrt() as f32,
-                    Color::BLUE.fade(0.3),
-                );
-            }
+    if let Some(spacing) = options.fidelity {
+        let balls = skeleton
+            .nodes
+            .iter()
+            .zip(skeleton.radii.iter())
+            .map(|(&center, &radius)| Sphere::new(center, radius));
+        let balls = within_bounds(balls, &vertices);
+        let surface = union_of_balls(&balls, spacing);
+        println!(
+            "hausdorff distance of the union of balls to the surface: {:.6}",
+            hausdorff_distance(&surface, &faces)
+        );
+    }
+    Ok(())
+}
 
-            if show_grid {
-                draw_handle.draw_grid(100, 10.0);
-            }
-        }
+fn bounds(points: &[Point]) -> (Point, Point) {
+    points.iter().fold((points[0], points[0]), |(min, max), p| {
+        (
+            Point::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z)),
+            Point::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z)),
+        )
+    })
+}
 
-        if show_ui {
-            let mut gui_y = (10..).step_by(35).map(|n| n as f32);
+// balls of nearly flat tetrahedra can be far larger than the shape, so only those centered in its
+// bounding box and no wider than it are kept
+fn within_bounds(balls: impl Iterator<Item = Sphere>, points: &[Point]) -> Vec<Sphere> {
+    let (min, max) = bounds(points);
+    balls
+        .filter(|Sphere { center, radius }| {
+            (min.x..=max.x).contains(&center.x)
+                && (min.y..=max.y).contains(&center.y)
+                && (min.z..=max.z).contains(&center.z)
+                && *radius <= min.distance(&max) / 2.0
+        })
+        .collect()
+}
 
-            show_outline = draw_handle.gui_check_box(
-                Rectangle::new(10.0, gui_y.next().unwrap(), 30.0, 30.0),
-                Some(rstr!("show outline")),
-                show_outline,
-            );
-            show_vertices = draw_handle.gui_check_box(
-                Rectangle::new(10.0, gui_y.next().unwrap(), 30.0, 30.0),
-                Some(rstr!("show vertices")),
-                show_vertices,
-            );
-            show_delaunay = draw_handle.gui_check_box(
-                Rectangle::new(10.0, gui_y.next().unwrap(), 30.0, 30.0),
-                Some(rstr!("show delaunay")),
-                show_delaunay,
-            );
-            show_skeleton = draw_handle.gui_check_box(
-                Rectangle::new(10.0, gui_y.next().unwrap(), 30.0, 30.0),
-                Some(rstr!("show skeleton")),
-                show_skeleton,
-            );
-            show_skeleton_balls = draw_handle.gui_check_box(
-                Rectangle::new(10.0, gui_y.next().unwrap(), 30.0, 30.0),
-                Some(rstr!("show skeleton balls")),
-                show_skeleton_balls,
-            );
-            show_spheres = draw_handle.gui_check_box(
-                Rectangle::new(10.0, gui_y.next().unwrap(), 30.0, 30.0),
-                Some(rstr!("show spheres")),
-                show_spheres,
-            );
-            if draw_handle.gui_button(
-                Rectangle::new(10.0, gui_y.next().unwrap(), 30.0, 30.0),
-                Some(rstr!("{}", tetra_index).as_c_str()),
-            ) {
-                tetra_index = tetra_iter.next().unwrap();
-            }
-            show_grid = draw_handle.gui_check_box(
-                Rectangle::new(10.0, gui_y.next().unwrap(), 30.0, 30.0),
-                Some(rstr!("show grid")),
-                show_grid,
-            );
+fn spheres(input: &str, output: &str, options: &Options) -> Result<(), String> {
+    let (points, faces, tetrahedrons) = load_tetrahedrons(input, options)?;
+    let surface = if faces.is_empty() {
+        points
+    } else {
+        repair::face_vertices(&faces)
+    };
+    let max_error = options.max_error.unwrap_or_else(|| {
+        let (min, max) = bounds(&surface);
+        min.distance(&max) / 100.0
+    });
+
+    let balls = within_bounds(medial_balls(&tetrahedrons).into_iter(), &surface);
+    let selected = select_spheres(&balls, &surface, options.sphere_count, max_error);
+    io::spheres::save(output, &selected).map_err(|error| format!("{}: {}", output, error))?;
+
+    let covered = surface
+        .iter()
+        .filter(|point| {
+            selected
+                .iter()
+                .any(|sphere| sphere.covers(point, max_error))
+        })
+        .count();
+    println!("{} spheres written to {}", selected.len(), output);
+    println!(
+        "{} of {} surface points covered within {:.6}",
+        covered,
+        surface.len(),
+        max_error
+    );
+    Ok(())
+}
+
+fn validate(input: &str) -> Result<(), String> {
+    let Mesh { points, faces } =
+        load_mesh(input).map_err(|error| format!("{}: {}", input, error))?;
+
+    let degenerate = faces
+        .iter()
+        .filter(|face| repair::is_degenerate(face))
+        .count();
+    let duplicate = faces.len() - repair::remove_duplicate_faces(&faces).len();
+    let non_manifold = repair::non_manifold_edges(&faces).len();
+    let holes = repair::boundary_loops(&faces).len();
+
+    println!("points: {}", points.len());
+    println!("faces: {}", faces.len());
+    println!("degenerate faces: {}", degenerate);
+    println!("duplicate faces: {}", duplicate);
+    println!("non-manifold edges: {}", non_manifold);
+    println!("holes: {}", holes);
+
+    if faces.is_empty() {
+        Err(format!("{}: no faces", input))
+    } else if degenerate + duplicate + non_manifold + holes > 0 {
+        Err(format!("{}: surface is not a closed manifold", input))
+    } else {
+        Ok(())
+    }
+}
+
+fn convert(input: &str, output: &str) -> Result<(), String> {
+    match (is_tet_mesh(input), is_tet_mesh(output)) {
+        (true, true) => {
+            let mesh = load_tet_mesh(input).map_err(|error| format!("{}: {}", input, error))?;
+            save_tet_mesh(output, &mesh).map_err(|error| format!("{}: {}", output, error))
+        }
+        (false, false) => {
+            let mesh = load_mesh(input).map_err(|error| format!("{}: {}", input, error))?;
+            save_mesh(output, &mesh).map_err(|error| format!("{}: {}", output, error))
         }
+        _ => Err(format!(
+            "cannot convert between a surface and a tetrahedral mesh ({} to {})",
+            input, output
+        )),
     }
 }
+
+#[cfg(feature = "viewer")]
+fn view(input: &str, options: &Options) -> Result<(), String> {
+    let (points, faces, tetrahedrons) = load_tetrahedrons(input, options)?;
+    if tetrahedrons.is_empty() {
+        return Err(format!("{}: no tetrahedrons to show", input));
+    }
+
+    medial_axis_3d::viewer::run(&points, &faces, &tetrahedrons);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "viewer"))]
+fn view(_: &str, _: &Options) -> Result<(), String> {
+    Err("built without the viewer, rebuild with --features viewer".to_string())
+}
@@ -1,36 +1,63 @@
-use super::almost_equal;
-use raylib::math::Vector3;
-use std::hash::{Hash, Hasher};
+use super::{almost_equal, scalar::Scalar};
+use std::{
+    hash::{Hash, Hasher},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
 
 #[derive(Copy, Clone, Debug)]
-pub struct Point {
-    pub x: f64,
-    pub y: f64,
-    pub z: f64,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point<T = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
 }
 
-impl Point {
-    pub fn new(x: f64, y: f64, z: f64) -> Self {
+impl<T: Scalar> Point<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
         Self { x, y, z }
     }
 
-    pub fn dist(&self, other: &Point) -> f64 {
+    pub fn dist(&self, other: &Self) -> T {
         let dx = self.x - other.x;
         let dy = self.y - other.y;
         let dz = self.z - other.z;
         dx * dx + dy * dy + dz * dz
     }
 
-    pub fn norm(&self) -> f64 {
+    pub fn norm(&self) -> T {
         self.x * self.x + self.y * self.y + self.z * self.z
     }
 
+    pub fn length(&self) -> T {
+        self.norm().sqrt()
+    }
+
+    pub fn distance(&self, other: &Self) -> T {
+        self.dist(other).sqrt()
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
     pub fn is_normal(&self) -> bool {
         self.x.is_normal() && self.y.is_normal() && self.z.is_normal()
     }
+
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
 }
 
-impl PartialEq for Point {
+impl<T: Scalar> PartialEq for Point<T> {
     fn eq(&self, other: &Self) -> bool {
         almost_equal(self.x, other.x)
             && almost_equal(self.y, other.y)
@@ -38,22 +65,107 @@ impl PartialEq for Point {
     }
 }
 
-impl Eq for Point {}
+impl<T: Scalar> Eq for Point<T> {}
 
-impl Hash for Point {
+impl<T: Scalar> Hash for Point<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        (self.x.to_bits(), self.y.to_bits(), self.z.to_bits()).hash(state);
+        (
+            self.x.to_f64().to_bits(),
+            self.y.to_f64().to_bits(),
+            self.z.to_f64().to_bits(),
+        )
+            .hash(state);
+    }
+}
+
+impl<T: Scalar> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Scalar> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Scalar> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl<T: Scalar> Div<T> for Point<T> {
+    type Output = Self;
+
+    fn div(self, divisor: T) -> Self {
+        Self::new(self.x / divisor, self.y / divisor, self.z / divisor)
     }
 }
 
-impl From<Point> for Vector3 {
-    fn from(Point { x, y, z }: Point) -> Self {
-        Self::new(x as f32, y as f32, z as f32)
+impl<T: Scalar> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Scalar + nalgebra::Scalar> From<Point<T>> for nalgebra::Point3<T> {
+    fn from(Point { x, y, z }: Point<T>) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl<T: Scalar + nalgebra::Scalar> From<nalgebra::Point3<T>> for Point<T> {
+    fn from(point: nalgebra::Point3<T>) -> Self {
+        Self::new(point.x, point.y, point.z)
+    }
+}
+
+impl<T: Scalar + nalgebra::Scalar> From<Point<T>> for nalgebra::Vector3<T> {
+    fn from(Point { x, y, z }: Point<T>) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl<T: Scalar + nalgebra::Scalar> From<nalgebra::Vector3<T>> for Point<T> {
+    fn from(vector: nalgebra::Vector3<T>) -> Self {
+        Self::new(vector.x, vector.y, vector.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T: Scalar> From<Point<T>> for mint::Point3<T> {
+    fn from(Point { x, y, z }: Point<T>) -> Self {
+        Self { x, y, z }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T: Scalar> From<mint::Point3<T>> for Point<T> {
+    fn from(mint::Point3 { x, y, z }: mint::Point3<T>) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T: Scalar> From<Point<T>> for mint::Vector3<T> {
+    fn from(Point { x, y, z }: Point<T>) -> Self {
+        Self { x, y, z }
     }
 }
 
-impl From<Vector3> for Point {
-    fn from(Vector3 { x, y, z }: Vector3) -> Self {
-        Self::new(x as f64, y as f64, z as f64)
+#[cfg(feature = "mint")]
+impl<T: Scalar> From<mint::Vector3<T>> for Point<T> {
+    fn from(mint::Vector3 { x, y, z }: mint::Vector3<T>) -> Self {
+        Self::new(x, y, z)
     }
 }
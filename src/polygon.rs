@@ -0,0 +1,115 @@
+use super::{face::Face, point::Point};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polygon {
+    pub points: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(points: Vec<Point>) -> Self {
+        Self { points }
+    }
+
+    pub fn normal(&self) -> Point {
+        let mut normal = Point::new(0.0, 0.0, 0.0);
+        for (i, a) in self.points.iter().enumerate() {
+            let b = &self.points[(i + 1) % self.points.len()];
+            normal.x += (a.y - b.y) * (a.z + b.z);
+            normal.y += (a.z - b.z) * (a.x + b.x);
+            normal.z += (a.x - b.x) * (a.y + b.y);
+        }
+        normal
+    }
+
+    fn projected(&self) -> Vec<(f64, f64)> {
+        let n = self.normal();
+        let length = n.norm().sqrt();
+        let n = if length > 0.0 {
+            Point::new(n.x / length, n.y / length, n.z / length)
+        } else {
+            Point::new(0.0, 0.0, 1.0)
+        };
+
+        let helper = if n.x.abs() < 0.9 {
+            Point::new(1.0, 0.0, 0.0)
+        } else {
+            Point::new(0.0, 1.0, 0.0)
+        };
+        let u = Face::new(Point::new(0.0, 0.0, 0.0), n, helper).normal();
+        let u_length = u.norm().sqrt();
+        let u = Point::new(u.x / u_length, u.y / u_length, u.z / u_length);
+        let v = Face::new(Point::new(0.0, 0.0, 0.0), n, u).normal();
+
+        self.points
+            .iter()
+            .map(|p| {
+                (
+                    p.x * u.x + p.y * u.y + p.z * u.z,
+                    p.x * v.x + p.y * v.y + p.z * v.z,
+                )
+            })
+            .collect()
+    }
+
+    // Ear clipping in the plane of the polygon. Collinear vertices enclose no area and are
+    // dropped without a face; `None` means the outline crosses itself, so any triangulation
+    // would overlap.
+    pub fn triangulate(&self) -> Option<Vec<Face>> {
+        fn cross(o: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+            (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+        }
+
+        fn inside(p: (f64, f64), a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> bool {
+            cross(a, b, p) >= 0.0 && cross(b, c, p) >= 0.0 && cross(c, a, p) >= 0.0
+        }
+
+        if self.points.len() < 3 {
+            return Some(Vec::new());
+        }
+
+        let projected = self.projected();
+        let epsilon = 1e-12
+            * projected
+                .iter()
+                .zip(projected.iter().cycle().skip(1))
+                .map(|(a, b)| (b.0 - a.0).powi(2) + (b.1 - a.1).powi(2))
+                .fold(0.0, f64::max);
+        let mut remaining = (0..self.points.len()).collect::<Vec<_>>();
+        let mut faces = Vec::with_capacity(self.points.len() - 2);
+
+        while remaining.len() >= 3 {
+            let n = remaining.len();
+            let corner = |i: usize| {
+                (
+                    remaining[(i + n - 1) % n],
+                    remaining[i],
+                    remaining[(i + 1) % n],
+                )
+            };
+            let area = |i: usize| {
+                let (a, b, c) = corner(i);
+                cross(projected[a], projected[b], projected[c])
+            };
+            let is_ear = |i: usize| {
+                let (a, b, c) = corner(i);
+                area(i) > epsilon
+                    && remaining
+                        .iter()
+                        .filter(|&&j| j != a && j != b && j != c)
+                        .all(|&j| !inside(projected[j], projected[a], projected[b], projected[c]))
+            };
+
+            if let Some(ear) = (0..n).find(|&i| is_ear(i)) {
+                let (a, b, c) = corner(ear);
+                faces.push(Face::new(self.points[a], self.points[b], self.points[c]));
+                remaining.remove(ear);
+            } else if let Some(collinear) = (0..n).find(|&i| area(i).abs() <= epsilon) {
+                remaining.remove(collinear);
+            } else {
+                return None;
+            }
+        }
+        Some(faces)
+    }
+}
@@ -0,0 +1,132 @@
+use super::tetrahedron::Tetrahedron;
+use std::fmt;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Metric {
+    Volume,
+    RadiusEdgeRatio,
+    AspectRatio,
+    MinDihedralAngle,
+    MaxDihedralAngle,
+    MinSolidAngle,
+}
+
+impl Metric {
+    pub fn evaluate(&self, tetra: &Tetrahedron) -> f64 {
+        let min = |values: &[f64]| values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = |values: &[f64]| values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        match self {
+            Metric::Volume => tetra.volume(),
+            Metric::RadiusEdgeRatio => tetra.radius_edge_ratio(),
+            Metric::AspectRatio => tetra.aspect_ratio(),
+            Metric::MinDihedralAngle => min(&tetra.dihedral_angles()).to_degrees(),
+            Metric::MaxDihedralAngle => max(&tetra.dihedral_angles()).to_degrees(),
+            Metric::MinSolidAngle => min(&tetra.solid_angles()),
+        }
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Metric::Volume => "volume",
+            Metric::RadiusEdgeRatio => "radius-edge ratio",
+            Metric::AspectRatio => "aspect ratio",
+            Metric::MinDihedralAngle => "min dihedral angle",
+            Metric::MaxDihedralAngle => "max dihedral angle",
+            Metric::MinSolidAngle => "min solid angle",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Histogram {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub bins: Vec<usize>,
+}
+
+impl Histogram {
+    pub fn new(values: &[f64], bin_count: usize) -> Self {
+        let values = values
+            .iter()
+            .copied()
+            .filter(|value| value.is_finite())
+            .collect::<Vec<_>>();
+        if values.is_empty() || bin_count == 0 {
+            return Self::default();
+        }
+
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+
+        let mut bins = vec![0; bin_count];
+        let width = (max - min) / bin_count as f64;
+        for value in values {
+            let bin = if width > 0.0 {
+                (((value - min) / width) as usize).min(bin_count - 1)
+            } else {
+                0
+            };
+            bins[bin] += 1;
+        }
+
+        Self {
+            min,
+            max,
+            mean,
+            bins,
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.bins.iter().sum()
+    }
+
+    pub fn bin_range(&self, bin: usize) -> (f64, f64) {
+        let width = (self.max - self.min) / self.bins.len() as f64;
+        (
+            self.min + width * bin as f64,
+            self.min + width * (bin + 1) as f64,
+        )
+    }
+}
+
+impl fmt::Display for Histogram {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const BAR_WIDTH: usize = 40;
+        let largest = self.bins.iter().copied().max().unwrap_or(0).max(1);
+        for (bin, &count) in self.bins.iter().enumerate() {
+            let (lo, hi) = self.bin_range(bin);
+            writeln!(
+                f,
+                "{:>12.4} .. {:<12.4} {:>8} {}",
+                lo,
+                hi,
+                count,
+                "#".repeat(count * BAR_WIDTH / largest)
+            )?;
+        }
+        write!(
+            f,
+            "min {:.4}  max {:.4}  mean {:.4}  count {}",
+            self.min,
+            self.max,
+            self.mean,
+            self.count()
+        )
+    }
+}
+
+pub fn quality_histogram(tetrahedrons: &[Tetrahedron], metric: Metric, bins: usize) -> Histogram {
+    let values = tetrahedrons
+        .iter()
+        .map(|tetra| metric.evaluate(tetra))
+        .collect::<Vec<_>>();
+    Histogram::new(&values, bins)
+}
@@ -0,0 +1,316 @@
+use super::{face::Face, point::Point, spheres::Sphere};
+
+// the six tetrahedra around the main diagonal of a cube, by corner index x + 2y + 4z, so the
+// tetrahedra of neighbouring cubes share their faces
+const CUBE_TETRAHEDRA: [[usize; 4]; 6] = [
+    [0, 1, 3, 7],
+    [0, 1, 5, 7],
+    [0, 2, 3, 7],
+    [0, 2, 6, 7],
+    [0, 4, 5, 7],
+    [0, 4, 6, 7],
+];
+
+type Sample = (usize, Point, f64);
+
+fn crossing(a: &Sample, b: &Sample) -> Point {
+    let (a, b) = if a.0 < b.0 { (a, b) } else { (b, a) };
+    a.1 + (b.1 - a.1) * (a.2 / (a.2 - b.2))
+}
+
+fn march(samples: &[Sample; 4], faces: &mut Vec<Face>) {
+    let (inside, outside): (Vec<&Sample>, Vec<&Sample>) =
+        samples.iter().partition(|sample| sample.2 < 0.0);
+    let polygon = match (inside.as_slice(), outside.as_slice()) {
+        ([a], [b, c, d]) | ([b, c, d], [a]) => {
+            vec![crossing(a, b), crossing(a, c), crossing(a, d)]
+        }
+        ([a, b], [c, d]) => vec![
+            crossing(a, c),
+            crossing(a, d),
+            crossing(b, d),
+            crossing(b, c),
+        ],
+        _ => return,
+    };
+
+    let center = |samples: &[&Sample]| {
+        samples
+            .iter()
+            .fold(Point::new(0.0, 0.0, 0.0), |sum, sample| sum + sample.1)
+            / samples.len() as f64
+    };
+    let outward = center(&outside) - center(&inside);
+    for i in 1..polygon.len() - 1 {
+        let face = Face::new(polygon[0], polygon[i], polygon[i + 1]);
+        faces.push(if face.normal().dot(&outward) < 0.0 {
+            Face::new(face.p1, face.p3, face.p2)
+        } else {
+            face
+        });
+    }
+}
+
+// marching tetrahedra over the signed distance to the union, which is only evaluated near each
+// ball, so grid nodes far outside every ball keep a positive placeholder
+pub fn union_of_balls(balls: &[Sphere], spacing: f64) -> Vec<Face> {
+    if balls.is_empty() || spacing <= 0.0 {
+        return Vec::new();
+    }
+    let reach = 2.0 * spacing;
+
+    let mut min = Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+    let mut max = -min;
+    for ball in balls.iter() {
+        let extent = ball.radius + 2.0 * reach;
+        let extent = Point::new(extent, extent, extent);
+        let (lo, hi) = (ball.center - extent, ball.center + extent);
+        min = Point::new(min.x.min(lo.x), min.y.min(lo.y), min.z.min(lo.z));
+        max = Point::new(max.x.max(hi.x), max.y.max(hi.y), max.z.max(hi.z));
+    }
+
+    let count = |lo: f64, hi: f64| ((hi - lo) / spacing).ceil() as usize + 1;
+    let size = [
+        count(min.x, max.x),
+        count(min.y, max.y),
+        count(min.z, max.z),
+    ];
+    let node = |[i, j, k]: [usize; 3]| i + size[0] * (j + size[1] * k);
+    let position = |[i, j, k]: [usize; 3]| {
+        Point::new(
+            min.x + i as f64 * spacing,
+            min.y + j as f64 * spacing,
+            min.z + k as f64 * spacing,
+        )
+    };
+
+    let mut values = vec![reach; size[0] * size[1] * size[2]];
+    for ball in balls.iter() {
+        let extent = ball.radius + reach;
+        let range = |center: f64, lo: f64, n: usize| {
+            let first = ((center - extent - lo) / spacing).floor().max(0.0) as usize;
+            let last = (((center + extent - lo) / spacing).ceil() as usize).min(n - 1);
+            first..=last
+        };
+        for k in range(ball.center.z, min.z, size[2]) {
+            for j in range(ball.center.y, min.y, size[1]) {
+                for i in range(ball.center.x, min.x, size[0]) {
+                    let distance = position([i, j, k]).distance(&ball.center) - ball.radius;
+                    let value = &mut values[node([i, j, k])];
+                    if distance < *value {
+                        *value = distance;
+                    }
+                }
+            }
+        }
+    }
+
+    // values on the surface are nudged outside, so crossings never land on a grid node and
+    // collapse the triangles around it
+    for value in values.iter_mut() {
+        if value.abs() < 1e-6 * spacing {
+            *value = 1e-6 * spacing;
+        }
+    }
+
+    let mut faces = Vec::new();
+    for k in 0..size[2] - 1 {
+        for j in 0..size[1] - 1 {
+            for i in 0..size[0] - 1 {
+                let corners = [0, 1, 2, 3, 4, 5, 6, 7]
+                    .map(|c| [i + (c & 1), j + ((c >> 1) & 1), k + ((c >> 2) & 1)]);
+                let inside = corners
+                    .iter()
+                    .filter(|&&corner| values[node(corner)] < 0.0)
+                    .count();
+                if inside == 0 || inside == 8 {
+                    continue;
+                }
+                for tetra in CUBE_TETRAHEDRA.iter() {
+                    let samples = tetra.map(|c| {
+                        let index = node(corners[c]);
+                        (index, position(corners[c]), values[index])
+                    });
+                    march(&samples, &mut faces);
+                }
+            }
+        }
+    }
+
+    faces
+}
+
+fn closest_point_on_face(point: &Point, Face { p1, p2, p3 }: &Face) -> Point {
+    let (ab, ac, ap) = (*p2 - *p1, *p3 - *p1, *point - *p1);
+    let (d1, d2) = (ab.dot(&ap), ac.dot(&ap));
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return *p1;
+    }
+    let bp = *point - *p2;
+    let (d3, d4) = (ab.dot(&bp), ac.dot(&bp));
+    if d3 >= 0.0 && d4 <= d3 {
+        return *p2;
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return *p1 + ab * (d1 / (d1 - d3));
+    }
+    let cp = *point - *p3;
+    let (d5, d6) = (ab.dot(&cp), ac.dot(&cp));
+    if d6 >= 0.0 && d5 <= d6 {
+        return *p3;
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return *p1 + ac * (d2 / (d2 - d6));
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+        return *p2 + (*p3 - *p2) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+    let denominator = va + vb + vc;
+    if denominator == 0.0 {
+        return *p1;
+    }
+    *p1 + ab * (vb / denominator) + ac * (vc / denominator)
+}
+
+const LEAF_SIZE: usize = 8;
+
+struct Node {
+    min: Point,
+    max: Point,
+    start: usize,
+    end: usize,
+    children: Option<(usize, usize)>,
+}
+
+impl Node {
+    fn distance(&self, point: &Point) -> f64 {
+        let gap = |value: f64, lo: f64, hi: f64| (lo - value).max(value - hi).max(0.0);
+        Point::new(
+            gap(point.x, self.min.x, self.max.x),
+            gap(point.y, self.min.y, self.max.y),
+            gap(point.z, self.min.z, self.max.z),
+        )
+        .length()
+    }
+}
+
+struct FaceTree<'a> {
+    faces: &'a [Face],
+    order: Vec<usize>,
+    nodes: Vec<Node>,
+}
+
+impl<'a> FaceTree<'a> {
+    fn new(faces: &'a [Face]) -> Self {
+        let mut tree = Self {
+            faces,
+            order: (0..faces.len()).collect(),
+            nodes: Vec::new(),
+        };
+        tree.build(0, faces.len());
+        tree
+    }
+
+    fn build(&mut self, start: usize, end: usize) -> usize {
+        let faces = self.faces;
+        let mut min = Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+        let mut max = -min;
+        for point in self.order[start..end]
+            .iter()
+            .flat_map(|&i| [faces[i].p1, faces[i].p2, faces[i].p3])
+        {
+            min = Point::new(min.x.min(point.x), min.y.min(point.y), min.z.min(point.z));
+            max = Point::new(max.x.max(point.x), max.y.max(point.y), max.z.max(point.z));
+        }
+
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            min,
+            max,
+            start,
+            end,
+            children: None,
+        });
+        if end - start > LEAF_SIZE {
+            let size = max - min;
+            let axis = |point: Point| {
+                if size.x >= size.y && size.x >= size.z {
+                    point.x
+                } else if size.y >= size.z {
+                    point.y
+                } else {
+                    point.z
+                }
+            };
+            let middle = (start + end) / 2;
+            self.order[start..end].select_nth_unstable_by(middle - start, |&a, &b| {
+                axis(faces[a].centroid())
+                    .partial_cmp(&axis(faces[b].centroid()))
+                    .unwrap()
+            });
+            let children = (self.build(start, middle), self.build(middle, end));
+            self.nodes[index].children = Some(children);
+        }
+        index
+    }
+
+    // nearest nodes are searched first, and the search stops once the distance is known to be
+    // below the cutoff
+    fn distance(&self, point: &Point, cutoff: f64) -> f64 {
+        let mut best = f64::INFINITY;
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if best <= cutoff || node.distance(point) >= best {
+                continue;
+            }
+            match node.children {
+                Some((a, b)) => {
+                    let (near, far) =
+                        if self.nodes[a].distance(point) <= self.nodes[b].distance(point) {
+                            (a, b)
+                        } else {
+                            (b, a)
+                        };
+                    stack.push(far);
+                    stack.push(near);
+                }
+                None => {
+                    for &i in self.order[node.start..node.end].iter() {
+                        let closest = closest_point_on_face(point, &self.faces[i]);
+                        best = best.min(point.distance(&closest));
+                    }
+                }
+            }
+        }
+        best
+    }
+}
+
+// sampled at the vertices, edge midpoints and centroid of every face of `from`
+pub fn directed_hausdorff_distance(from: &[Face], to: &[Face]) -> f64 {
+    if from.is_empty() || to.is_empty() {
+        return if from.is_empty() { 0.0 } else { f64::INFINITY };
+    }
+    let tree = FaceTree::new(to);
+    from.iter()
+        .flat_map(|face| {
+            [
+                face.p1,
+                face.p2,
+                face.p3,
+                (face.p1 + face.p2) / 2.0,
+                (face.p2 + face.p3) / 2.0,
+                (face.p3 + face.p1) / 2.0,
+                face.centroid(),
+            ]
+        })
+        .fold(0.0, |max, point| tree.distance(&point, max).max(max))
+}
+
+pub fn hausdorff_distance(a: &[Face], b: &[Face]) -> f64 {
+    directed_hausdorff_distance(a, b).max(directed_hausdorff_distance(b, a))
+}
@@ -0,0 +1,371 @@
+use super::{
+    constrained::{
+        constrained_delaunay, coplanar, edge_key, longest_edge, split_subfaces,
+        ConstrainedDelaunay, ConstrainedError, PointKey,
+    },
+    face::Face,
+    point::Point,
+    tetrahedron::Tetrahedron,
+};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RefineOptions {
+    pub max_radius_edge_ratio: f64,
+    pub max_steiner_points: usize,
+    pub min_dihedral_angle: Option<f64>,
+}
+
+impl Default for RefineOptions {
+    fn default() -> Self {
+        Self {
+            max_radius_edge_ratio: 2.0,
+            max_steiner_points: 10_000,
+            min_dihedral_angle: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Refinement {
+    pub tetrahedrons: Vec<Tetrahedron>,
+    pub subfaces: Vec<Face>,
+    pub steiner_points: Vec<Point>,
+    // whether refinement stopped because the steiner points ran out while tetrahedra outside the
+    // protecting balls still miss the bounds
+    pub budget_exhausted: bool,
+}
+
+fn min_dihedral_angle(tetra: &Tetrahedron) -> f64 {
+    tetra
+        .dihedral_angles()
+        .iter()
+        .copied()
+        .fold(f64::INFINITY, f64::min)
+        .to_degrees()
+}
+
+fn encroaches(point: &Point, face: &Face) -> bool {
+    let center = face.circumcenter();
+    ![face.p1, face.p2, face.p3].contains(point)
+        && point.dist(&center) < center.dist(&face.p1) * (1.0 - 1e-9)
+}
+
+fn encroaches_segment(point: &Point, (a, b): &(Point, Point)) -> bool {
+    point != a && point != b && point.dist(&((*a + *b) / 2.0)) < a.dist(b) / 4.0 * (1.0 - 1e-9)
+}
+
+// the edges where the surface bends, which stay fixed while the subfaces of the planar facets
+// between them are retriangulated
+fn segments(subfaces: &[Face]) -> Vec<(Point, Point)> {
+    let mut edges = HashMap::<_, (Point, Point, Vec<&Face>)>::new();
+    for face in subfaces.iter() {
+        let Face { p1, p2, p3 } = *face;
+        for &(a, b) in [(p1, p2), (p2, p3), (p3, p1)].iter() {
+            edges
+                .entry(edge_key(&a, &b))
+                .or_insert_with(|| (a, b, Vec::new()))
+                .2
+                .push(face);
+        }
+    }
+    edges
+        .into_values()
+        .filter(|(_, _, faces)| !matches!(faces[..], [f, g] if coplanar(f, g)))
+        .map(|(a, b, _)| (a, b))
+        .collect()
+}
+
+// balls around the corners of the surface sharper than 60 degrees, measured within their facets.
+// Tetrahedra in such a corner cannot all meet the bound and refining towards it never ends, so no
+// point is inserted inside these balls.
+fn protecting_balls(shape: &[Face]) -> Vec<(Point, f64)> {
+    let mut corners = HashMap::<Point, (Vec<(Face, f64)>, f64)>::new();
+    for face in shape.iter() {
+        let Face { p1, p2, p3 } = *face;
+        for &(corner, a, b) in [(p1, p2, p3), (p2, p3, p1), (p3, p1, p2)].iter() {
+            let (u, v) = (a - corner, b - corner);
+            let angle = (u.dot(&v) / (u.length() * v.length())).acos();
+            let (fans, shortest) = corners
+                .entry(corner)
+                .or_insert_with(|| (Vec::new(), f64::INFINITY));
+            *shortest = shortest.min(u.length()).min(v.length());
+            match fans.iter_mut().find(|(other, _)| coplanar(face, other)) {
+                Some((_, sum)) => *sum += angle,
+                None => fans.push((*face, angle)),
+            }
+        }
+    }
+    corners
+        .into_iter()
+        .filter(|(_, (fans, _))| fans.iter().any(|&(_, angle)| angle.to_degrees() < 60.0))
+        .map(|(corner, (_, shortest))| (corner, shortest / 4.0))
+        .collect()
+}
+
+// how far along the segment it crosses the face, with a little slack so a segment through an edge
+// of the surface still hits one of its faces
+fn crossing(from: &Point, to: &Point, face: &Face) -> Option<f64> {
+    const SLACK: f64 = 1e-9;
+
+    let (u, v, direction) = (face.p2 - face.p1, face.p3 - face.p1, *to - *from);
+    let p = direction.cross(&v);
+    let determinant = u.dot(&p);
+    if determinant == 0.0 {
+        return None;
+    }
+    let offset = *from - face.p1;
+    let q = offset.cross(&u);
+    let (a, b) = (
+        offset.dot(&p) / determinant,
+        direction.dot(&q) / determinant,
+    );
+    let t = v.dot(&q) / determinant;
+    (a >= -SLACK && b >= -SLACK && a + b <= 1.0 + SLACK && t > 0.0 && t <= 1.0 + SLACK).then_some(t)
+}
+
+enum Split {
+    Edge(Point, Point, Point),
+    Face(Face, Point),
+    Tetrahedron(Point),
+}
+
+impl Split {
+    fn point(&self) -> Point {
+        match *self {
+            Split::Edge(_, _, point) | Split::Face(_, point) | Split::Tetrahedron(point) => point,
+        }
+    }
+}
+
+// where a point of the plane of a face falls on it: inside, on an edge, where it is snapped onto
+// the edge, or outside
+fn locate(face: &Face, point: &Point) -> Option<Split> {
+    const SNAP: f64 = 1e-3;
+
+    let normal = face.normal();
+    let size = normal.norm().sqrt();
+    if normal.dot(&(*point - face.p1)).abs() > 1e-9 * size * size.sqrt() {
+        return None;
+    }
+    let Face { p1, p2, p3 } = *face;
+    let edges = [(p2, p3), (p3, p1), (p1, p2)];
+    let weights = edges.map(|(a, b)| (b - a).cross(&(*point - a)).dot(&normal) / normal.norm());
+    if weights.iter().any(|&weight| weight < -SNAP) {
+        return None;
+    }
+    match (0..3).find(|&i| weights[i] < SNAP) {
+        Some(i) => {
+            let (a, b) = edges[i];
+            let t = (*point - a).dot(&(b - a)) / a.dist(&b);
+            Some(Split::Edge(a, b, a + (b - a) * t.clamp(SNAP, 1.0 - SNAP)))
+        }
+        None => Some(Split::Face(*face, *point)),
+    }
+}
+
+// an encroached subface is split at its circumcenter within its facet, unless that would encroach
+// a segment, which is then split at its midpoint instead
+fn split_subface(face: &Face, subfaces: &[Face], segments: &[(Point, Point)]) -> Split {
+    let center = face.circumcenter();
+    if let Some(&(a, b)) = segments
+        .iter()
+        .find(|segment| encroaches_segment(&center, segment))
+    {
+        return Split::Edge(a, b, (a + b) / 2.0);
+    }
+    subfaces
+        .iter()
+        .filter(|other| coplanar(face, other))
+        .find_map(|other| locate(other, &center))
+        .unwrap_or_else(|| {
+            let (a, b) = longest_edge(face);
+            Split::Edge(a, b, (a + b) / 2.0)
+        })
+}
+
+#[derive(Default)]
+struct Splits {
+    edges: HashMap<(PointKey, PointKey), Point>,
+    faces: HashMap<Face, Point>,
+    inserted: Vec<Point>,
+}
+
+impl Splits {
+    fn add(&mut self, split: Split) -> Point {
+        match split {
+            Split::Edge(a, b, point) => *self.edges.entry(edge_key(&a, &b)).or_insert(point),
+            Split::Face(face, point) => *self.faces.entry(face).or_insert(point),
+            Split::Tetrahedron(point) => {
+                self.inserted.push(point);
+                point
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.edges.len() + self.faces.len() + self.inserted.len()
+    }
+
+    fn subfaces(&self, subfaces: &[Face]) -> Vec<Face> {
+        let subfaces = subfaces
+            .iter()
+            .flat_map(|face| match self.faces.get(face) {
+                Some(&point) => vec![
+                    Face::new(face.p1, face.p2, point),
+                    Face::new(face.p2, face.p3, point),
+                    Face::new(face.p3, face.p1, point),
+                ],
+                None => vec![*face],
+            })
+            .collect::<Vec<_>>();
+        split_subfaces(&subfaces, &self.edges)
+    }
+}
+
+// Delaunay refinement after Shewchuk: the circumcenter of every poor tetrahedron is inserted,
+// unless it lies across a subface from the tetrahedron or encroaches one, in which case that
+// subface is split instead. Refinement stops once every tetrahedron outside the protecting balls
+// meets the bounds, or the steiner points run out, which the refinement reports.
+pub fn refine(
+    points: &[Point],
+    shape: &[Face],
+    options: &RefineOptions,
+) -> Result<Refinement, ConstrainedError> {
+    let is_poor = |tetra: &Tetrahedron| {
+        tetra.radius_edge_ratio() > options.max_radius_edge_ratio
+            || options
+                .min_dihedral_angle
+                .is_some_and(|angle| min_dihedral_angle(tetra) < angle)
+    };
+    let balls = protecting_balls(shape);
+    let protected = |point: &Point| {
+        balls
+            .iter()
+            .any(|(corner, radius)| point.distance(corner) < *radius)
+    };
+
+    let mut constrained = constrained_delaunay(points, shape, options.max_steiner_points)?;
+    let mut surface = points
+        .iter()
+        .chain(constrained.steiner_points.iter())
+        .copied()
+        .collect::<Vec<_>>();
+    let mut free = Vec::new();
+    let mut batch = usize::MAX;
+
+    loop {
+        let remaining = options
+            .max_steiner_points
+            .saturating_sub(constrained.steiner_points.len());
+        let mut poor = constrained
+            .interior()
+            .into_iter()
+            .filter(|tetra| is_poor(tetra))
+            .collect::<Vec<_>>();
+        poor.sort_by(|a, b| {
+            b.radius_edge_ratio()
+                .partial_cmp(&a.radius_edge_ratio())
+                .unwrap()
+        });
+
+        // worst first, skipping tetrahedra that an earlier point of the round already destroys
+        let subfaces = &constrained.subfaces;
+        let segments = segments(subfaces);
+        let mut splits = Splits::default();
+        let mut chosen = Vec::<Point>::new();
+        for tetra in poor.iter() {
+            if chosen.len() >= batch.min(remaining) {
+                break;
+            }
+            let center = tetra.circumcenter;
+            if chosen
+                .iter()
+                .any(|point| point.dist(&center) < tetra.circumradius)
+            {
+                continue;
+            }
+
+            let centroid = tetra.centroid();
+            let encroached = subfaces
+                .iter()
+                .filter_map(|face| crossing(&centroid, &center, face).map(|t| (t, face)))
+                .min_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap())
+                .map(|(_, face)| face)
+                .or_else(|| subfaces.iter().find(|face| encroaches(&center, face)));
+            let split = match encroached {
+                Some(face) => split_subface(face, subfaces, &segments),
+                None => Split::Tetrahedron(center),
+            };
+            if !protected(&split.point()) {
+                chosen.push(splits.add(split));
+            }
+        }
+
+        let count = splits.len();
+        if count == 0 {
+            break;
+        }
+        let trial_subfaces = splits.subfaces(subfaces);
+
+        // free vertices in the diametral ball of a new subface are deleted, as recovering it
+        // around them would split the surface down to their distance from it
+        let old = subfaces.iter().collect::<HashSet<_>>();
+        let new = trial_subfaces
+            .iter()
+            .filter(|face| !old.contains(face))
+            .collect::<Vec<_>>();
+        let trial_free = free
+            .iter()
+            .chain(splits.inserted.iter())
+            .filter(|point| !new.iter().any(|face| encroaches(point, face)))
+            .copied()
+            .collect::<Vec<_>>();
+        let mut trial_surface = surface.clone();
+        trial_surface.extend(splits.edges.values().chain(splits.faces.values()));
+        let trial_points = trial_surface
+            .iter()
+            .chain(trial_free.iter())
+            .copied()
+            .collect::<Vec<_>>();
+
+        match constrained_delaunay(
+            &trial_points,
+            &trial_subfaces,
+            (remaining - count).min(trial_points.len()),
+        ) {
+            Ok(trial) => {
+                trial_surface.extend(trial.steiner_points.iter());
+                surface = trial_surface;
+                free = trial_free;
+                constrained = ConstrainedDelaunay {
+                    steiner_points: surface[points.len()..]
+                        .iter()
+                        .chain(free.iter())
+                        .copied()
+                        .collect(),
+                    ..trial
+                };
+                batch = count.saturating_mul(2);
+            }
+            // recovery can run away near sharp features, so such a round is rolled back and
+            // retried with fewer points
+            Err(_) if count > 1 => batch = count / 2,
+            Err(_) => break,
+        }
+    }
+
+    let tetrahedrons = constrained.interior();
+    let budget_exhausted = constrained.steiner_points.len() >= options.max_steiner_points
+        && tetrahedrons
+            .iter()
+            .any(|tetra| is_poor(tetra) && !protected(&tetra.circumcenter));
+    Ok(Refinement {
+        tetrahedrons,
+        subfaces: constrained.subfaces,
+        steiner_points: constrained.steiner_points,
+        budget_exhausted,
+    })
+}
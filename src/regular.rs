@@ -0,0 +1,157 @@
+use super::{
+    constrained::ConstrainedError,
+    edge::Edge,
+    insert,
+    point::Point,
+    super_tetrahedron,
+    tetrahedron::Tetrahedron,
+    voronoi::{power_cells, Bounds, VoronoiCell},
+};
+use std::collections::{HashMap, HashSet};
+
+// weights are squared radii, so the power distance of x to (p, w) is |x - p|² - w
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightedTetrahedron {
+    pub tetra: Tetrahedron,
+    pub weights: [f64; 4],
+    pub orthocenter: Point,
+    pub orthoradius: f64,
+}
+
+impl WeightedTetrahedron {
+    pub fn new(tetra: Tetrahedron, weights: [f64; 4]) -> Self {
+        let [w1, w2, w3, w4] = weights;
+        let (a, b, c) = (
+            tetra.p2 - tetra.p1,
+            tetra.p3 - tetra.p1,
+            tetra.p4 - tetra.p1,
+        );
+        let (bc, ca, ab) = (b.cross(&c), c.cross(&a), a.cross(&b));
+        let alpha = 2.0 * a.dot(&bc);
+
+        let offset =
+            (bc * (a.norm() + w1 - w2) + ca * (b.norm() + w1 - w3) + ab * (c.norm() + w1 - w4))
+                / alpha;
+
+        Self {
+            tetra,
+            weights,
+            orthocenter: tetra.p1 + offset,
+            orthoradius: offset.norm() - w1,
+        }
+    }
+
+    pub fn power(&self, point: &Point, weight: f64) -> f64 {
+        point.dist(&self.orthocenter) - self.orthoradius - weight
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegularTriangulation {
+    pub points: Vec<(Point, f64)>,
+    pub tetrahedrons: Vec<WeightedTetrahedron>,
+    pub hidden: Vec<usize>,
+}
+
+// points that end up with no tetrahedron, either never inserted or removed from the cavity of a
+// heavier point, are the hidden ones
+pub fn regular_triangulation(points: &[(Point, f64)]) -> RegularTriangulation {
+    let mut heaviest = HashMap::<Point, f64>::new();
+    for &(point, weight) in points.iter() {
+        let entry = heaviest.entry(point).or_insert(weight);
+        *entry = entry.max(weight);
+    }
+
+    let positions = points.iter().map(|&(point, _)| point).collect::<Vec<_>>();
+    let super_tetra = super_tetrahedron(&positions);
+    let weight = |point: &Point| heaviest.get(point).copied().unwrap_or(0.0);
+    let weighted = |tetra: &Tetrahedron| {
+        WeightedTetrahedron::new(
+            *tetra,
+            [tetra.p1, tetra.p2, tetra.p3, tetra.p4].map(|point| weight(&point)),
+        )
+    };
+
+    let mut tetrahedrons = vec![super_tetra];
+    let mut inserted = HashSet::new();
+    for (point, weight) in points.iter() {
+        if heaviest[point] == *weight && inserted.insert(*point) {
+            insert(&mut tetrahedrons, point, |tetra| {
+                weighted(tetra).power(point, *weight) < 0.0
+            });
+        }
+    }
+
+    let tetrahedrons = tetrahedrons
+        .iter()
+        .filter(|tetra| {
+            [
+                super_tetra.p1,
+                super_tetra.p2,
+                super_tetra.p3,
+                super_tetra.p4,
+            ]
+            .iter()
+            .all(|p| !tetra.has_point(p))
+        })
+        .map(weighted)
+        .collect::<Vec<_>>();
+
+    let mut vertices = HashMap::new();
+    for tetra in tetrahedrons.iter() {
+        let tetra = tetra.tetra;
+        for point in [tetra.p1, tetra.p2, tetra.p3, tetra.p4].iter() {
+            vertices.insert(*point, weight(point));
+        }
+    }
+    let hidden = points
+        .iter()
+        .enumerate()
+        .filter(|(_, (point, weight))| vertices.get(point) != Some(weight))
+        .map(|(i, _)| i)
+        .collect();
+
+    RegularTriangulation {
+        points: points.to_vec(),
+        tetrahedrons,
+        hidden,
+    }
+}
+
+impl RegularTriangulation {
+    pub fn power_edges(&self) -> Vec<Edge> {
+        let mut faces = HashMap::new();
+        let mut edges = Vec::new();
+        for tetra in self.tetrahedrons.iter() {
+            for face in tetra.tetra.faces() {
+                if let Some(other) = faces.insert(face, tetra.orthocenter) {
+                    edges.push(Edge::new(other, tetra.orthocenter));
+                }
+            }
+        }
+        edges
+    }
+
+    pub fn power_cells(&self, bounds: &Bounds) -> Result<Vec<VoronoiCell>, ConstrainedError> {
+        let tetrahedrons = self
+            .tetrahedrons
+            .iter()
+            .map(|tetra| tetra.tetra)
+            .collect::<Vec<_>>();
+        let weights = self
+            .tetrahedrons
+            .iter()
+            .flat_map(|tetra| {
+                let Tetrahedron { p1, p2, p3, p4, .. } = tetra.tetra;
+                [p1, p2, p3, p4]
+                    .iter()
+                    .copied()
+                    .zip(tetra.weights.iter().copied())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<HashMap<_, _>>();
+        power_cells(&tetrahedrons, |point| weights[point], bounds)
+    }
+}
@@ -0,0 +1,268 @@
+use super::{face::Face, point::Point, polygon::Polygon};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+type PointKey = (u64, u64, u64);
+
+fn key(point: &Point) -> PointKey {
+    (point.x.to_bits(), point.y.to_bits(), point.z.to_bits())
+}
+
+fn edge_key(a: &Point, b: &Point) -> (PointKey, PointKey) {
+    let (a, b) = (key(a), key(b));
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn edges(face: &Face) -> [(Point, Point); 3] {
+    [(face.p1, face.p2), (face.p2, face.p3), (face.p3, face.p1)]
+}
+
+fn flipped(face: &Face) -> Face {
+    Face::new(face.p1, face.p3, face.p2)
+}
+
+fn signed_volume(faces: &[Face]) -> f64 {
+    faces
+        .iter()
+        .map(|Face { p1, p2, p3 }| {
+            p1.x * (p2.y * p3.z - p2.z * p3.y) - p1.y * (p2.x * p3.z - p2.z * p3.x)
+                + p1.z * (p2.x * p3.y - p2.y * p3.x)
+        })
+        .sum::<f64>()
+        / 6.0
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RepairOptions {
+    pub weld_tolerance: Option<f64>,
+    pub remove_degenerate: bool,
+    pub remove_duplicates: bool,
+    pub fix_orientation: bool,
+    pub fill_holes: bool,
+}
+
+impl Default for RepairOptions {
+    fn default() -> Self {
+        Self {
+            weld_tolerance: Some(1e-6),
+            remove_degenerate: true,
+            remove_duplicates: true,
+            fix_orientation: true,
+            fill_holes: true,
+        }
+    }
+}
+
+pub fn repair(faces: &[Face], options: &RepairOptions) -> Vec<Face> {
+    let mut faces = faces.to_vec();
+    if let Some(tolerance) = options.weld_tolerance {
+        faces = weld_vertices(&faces, tolerance);
+    }
+    if options.remove_degenerate {
+        faces = remove_degenerate_faces(&faces);
+    }
+    if options.remove_duplicates {
+        faces = remove_duplicate_faces(&faces);
+    }
+    if options.fix_orientation {
+        faces = fix_orientation(&faces);
+    }
+    if options.fill_holes {
+        faces = fill_holes(&faces);
+    }
+    faces
+}
+
+pub fn face_vertices(faces: &[Face]) -> Vec<Point> {
+    let mut seen = HashSet::new();
+    faces
+        .iter()
+        .flat_map(|face| vec![face.p1, face.p2, face.p3])
+        .filter(|point| seen.insert(key(point)))
+        .collect()
+}
+
+pub fn weld_points(points: &[Point], tolerance: f64) -> Vec<Point> {
+    let cell = |p: &Point| {
+        (
+            (p.x / tolerance).floor() as i64,
+            (p.y / tolerance).floor() as i64,
+            (p.z / tolerance).floor() as i64,
+        )
+    };
+
+    let mut grid: HashMap<(i64, i64, i64), Vec<Point>> = HashMap::new();
+    let mut welded = Vec::with_capacity(points.len());
+
+    for point in points {
+        let (cx, cy, cz) = cell(point);
+        let existing = (-1..=1)
+            .flat_map(|dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (dx, dy, dz))))
+            .filter_map(|(dx, dy, dz)| grid.get(&(cx + dx, cy + dy, cz + dz)))
+            .flatten()
+            .find(|other| other.dist(point) <= tolerance * tolerance)
+            .copied();
+
+        welded.push(existing.unwrap_or_else(|| {
+            grid.entry((cx, cy, cz)).or_default().push(*point);
+            *point
+        }));
+    }
+
+    welded
+}
+
+pub fn weld_vertices(faces: &[Face], tolerance: f64) -> Vec<Face> {
+    let points = faces
+        .iter()
+        .flat_map(|face| vec![face.p1, face.p2, face.p3])
+        .collect::<Vec<_>>();
+
+    weld_points(&points, tolerance)
+        .chunks(3)
+        .map(|chunk| Face::new(chunk[0], chunk[1], chunk[2]))
+        .collect()
+}
+
+pub fn is_degenerate(face: &Face) -> bool {
+    let Face { p1, p2, p3 } = face;
+    if p1 == p2 || p2 == p3 || p3 == p1 {
+        return true;
+    }
+    let longest = p1.dist(p2).max(p2.dist(p3)).max(p3.dist(p1));
+    face.normal().norm() <= f64::EPSILON * longest * longest
+}
+
+pub fn remove_degenerate_faces(faces: &[Face]) -> Vec<Face> {
+    faces
+        .iter()
+        .filter(|face| !is_degenerate(face))
+        .copied()
+        .collect()
+}
+
+pub fn remove_duplicate_faces(faces: &[Face]) -> Vec<Face> {
+    let mut seen = HashSet::new();
+    faces
+        .iter()
+        .filter(|&&face| seen.insert(face))
+        .copied()
+        .collect()
+}
+
+pub fn fix_orientation(faces: &[Face]) -> Vec<Face> {
+    let mut edge_faces = HashMap::<_, Vec<usize>>::new();
+    for (i, face) in faces.iter().enumerate() {
+        for (a, b) in edges(face).iter() {
+            edge_faces.entry(edge_key(a, b)).or_default().push(i);
+        }
+    }
+
+    let mut faces = faces.to_vec();
+    let mut visited = vec![false; faces.len()];
+
+    for start in 0..faces.len() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+
+        let mut component = vec![start];
+        let mut queue = VecDeque::from(vec![start]);
+        while let Some(current) = queue.pop_front() {
+            for (a, b) in edges(&faces[current]).iter() {
+                for &neighbour in edge_faces[&edge_key(a, b)].iter() {
+                    if visited[neighbour] {
+                        continue;
+                    }
+                    visited[neighbour] = true;
+                    let same_direction = edges(&faces[neighbour])
+                        .iter()
+                        .any(|(c, d)| c == a && d == b);
+                    if same_direction {
+                        faces[neighbour] = flipped(&faces[neighbour]);
+                    }
+                    component.push(neighbour);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        let component_faces = component.iter().map(|&i| faces[i]).collect::<Vec<_>>();
+        if signed_volume(&component_faces) < 0.0 {
+            for &i in component.iter() {
+                faces[i] = flipped(&faces[i]);
+            }
+        }
+    }
+
+    faces
+}
+
+pub fn boundary_loops(faces: &[Face]) -> Vec<Vec<Point>> {
+    let mut edge_count = HashMap::new();
+    for face in faces {
+        for (a, b) in edges(face).iter() {
+            *edge_count.entry(edge_key(a, b)).or_insert(0) += 1;
+        }
+    }
+
+    let mut next = HashMap::<_, Vec<Point>>::new();
+    let mut starts = Vec::new();
+    for face in faces {
+        for (a, b) in edges(face).iter() {
+            if edge_count[&edge_key(a, b)] == 1 {
+                next.entry(key(a)).or_default().push(*b);
+                starts.push(*a);
+            }
+        }
+    }
+
+    let mut loops = Vec::new();
+    for start in starts {
+        let mut current = start;
+        let mut boundary = Vec::new();
+        let mut closed = false;
+        while let Some(to) = next.get_mut(&key(&current)).and_then(Vec::pop) {
+            boundary.push(current);
+            current = to;
+            if current == start {
+                closed = true;
+                break;
+            }
+        }
+        if closed && boundary.len() >= 3 {
+            loops.push(boundary);
+        }
+    }
+
+    loops
+}
+
+pub fn non_manifold_edges(faces: &[Face]) -> Vec<(Point, Point)> {
+    let mut edge_faces = HashMap::<_, (Point, Point, usize)>::new();
+    for face in faces {
+        for &(a, b) in edges(face).iter() {
+            edge_faces.entry(edge_key(&a, &b)).or_insert((a, b, 0)).2 += 1;
+        }
+    }
+    edge_faces
+        .into_values()
+        .filter(|&(_, _, count)| count > 2)
+        .map(|(a, b, _)| (a, b))
+        .collect()
+}
+
+pub fn fill_holes(faces: &[Face]) -> Vec<Face> {
+    let mut filled = faces.to_vec();
+    for mut boundary in boundary_loops(faces) {
+        boundary.reverse();
+        // a boundary that crosses itself is left open rather than covered by overlapping faces
+        filled.extend(Polygon::new(boundary).triangulate().unwrap_or_default());
+    }
+    filled
+}
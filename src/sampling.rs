@@ -0,0 +1,315 @@
+use super::{constrained::edge_key, delaunay, face::Face, point::Point, point_inside_shape};
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use std::f64::consts::PI;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Sampling {
+    Vertices,
+    Uniform { count: usize },
+    PoissonDisk { radius: f64 },
+    Adaptive { epsilon: f64 },
+}
+
+// samples are returned with the index of the face they were drawn from, and `sample_surface`
+// retriangulates every face through its samples so the surface can still be recovered from them
+pub fn sample_surface<R: Rng + ?Sized>(
+    points: &[Point],
+    faces: &[Face],
+    sampling: &Sampling,
+    rng: &mut R,
+) -> (Vec<Point>, Vec<Face>) {
+    let samples = match *sampling {
+        Sampling::Vertices => Vec::new(),
+        Sampling::Uniform { count } => uniform_samples(faces, count, rng),
+        Sampling::PoissonDisk { radius } => poisson_disk_samples(faces, radius, rng),
+        Sampling::Adaptive { epsilon } => adaptive_samples(points, faces, epsilon, rng),
+    };
+
+    let mut face_samples = HashMap::<usize, Vec<Point>>::new();
+    for (face, sample) in samples {
+        face_samples.entry(face).or_default().push(sample);
+    }
+    let faces = faces
+        .iter()
+        .enumerate()
+        .flat_map(|(i, face)| match face_samples.get(&i) {
+            Some(samples) => insert_samples(face, samples),
+            None => vec![*face],
+        })
+        .collect::<Vec<_>>();
+
+    // a sample that fell on an edge of its face is skipped by `insert_samples`, so it is not part
+    // of any subface and is left out here too
+    let vertices = faces
+        .iter()
+        .flat_map(|face| [face.p1, face.p2, face.p3])
+        .collect::<HashSet<_>>();
+    let points = points
+        .iter()
+        .copied()
+        .chain(
+            face_samples
+                .into_values()
+                .flatten()
+                .filter(|sample| vertices.contains(sample)),
+        )
+        .collect();
+    (points, faces)
+}
+
+// Bowyer-Watson within the plane of the face, whose edges stay the hull of its subfaces as long as
+// the samples are inside it. Samples on those edges would only split off zero-area subfaces, as
+// the edge itself is not split, and are skipped.
+fn insert_samples(face: &Face, samples: &[Point]) -> Vec<Face> {
+    let edges = [(face.p1, face.p2), (face.p2, face.p3), (face.p3, face.p1)];
+    let on_edge = |sample: &Point| {
+        edges.iter().any(|&(a, b)| {
+            let length = (b - a).norm();
+            (b - a).cross(&(*sample - a)).norm() <= 1e-18 * length * length
+        })
+    };
+
+    let mut faces = vec![*face];
+    for sample in samples.iter().filter(|sample| !on_edge(sample)) {
+        let (cavity, kept): (Vec<Face>, Vec<Face>) = faces.into_iter().partition(|face| {
+            let center = face.circumcenter();
+            sample.dist(&center) < center.dist(&face.p1)
+        });
+        let edges = cavity
+            .iter()
+            .flat_map(|face| [(face.p1, face.p2), (face.p2, face.p3), (face.p3, face.p1)])
+            .collect::<Vec<_>>();
+        faces = kept;
+        for &(a, b) in edges.iter() {
+            if !edges.contains(&(b, a)) {
+                faces.push(Face::new(a, b, *sample));
+            }
+        }
+    }
+    faces
+}
+
+fn sample_face<R: Rng + ?Sized>(Face { p1, p2, p3 }: &Face, rng: &mut R) -> Point {
+    let r1 = rng.gen::<f64>().sqrt();
+    let r2 = rng.gen::<f64>();
+    let (a, b, c) = (1.0 - r1, r1 * (1.0 - r2), r1 * r2);
+    Point::new(
+        a * p1.x + b * p2.x + c * p3.x,
+        a * p1.y + b * p2.y + c * p3.y,
+        a * p1.z + b * p2.z + c * p3.z,
+    )
+}
+
+// samples closer than a quarter of the mean spacing to another sample or to an edge are redrawn,
+// as they would only leave slivers in the tetrahedralization. Fewer than `count` samples are only
+// returned when the edges leave no room for them, e.g. on surfaces of many tiny faces.
+pub fn uniform_samples<R: Rng + ?Sized>(
+    faces: &[Face],
+    count: usize,
+    rng: &mut R,
+) -> Vec<(usize, Point)> {
+    let cumulative_areas = faces
+        .iter()
+        .scan(0.0, |total, face| {
+            *total += face.area();
+            Some(*total)
+        })
+        .collect::<Vec<_>>();
+
+    let total_area = match cumulative_areas.last() {
+        Some(&total_area) if total_area > 0.0 => total_area,
+        _ => return Vec::new(),
+    };
+
+    let radius = (total_area / count.max(1) as f64).sqrt() / 4.0;
+    let mut grid = SampleGrid::new(radius);
+    seed(&mut grid, faces, |_| radius);
+
+    const ATTEMPTS_PER_SAMPLE: usize = 100;
+    let mut samples = Vec::with_capacity(count);
+    for _ in 0..count.saturating_mul(ATTEMPTS_PER_SAMPLE) {
+        if samples.len() == count {
+            break;
+        }
+        let target = rng.gen::<f64>() * total_area;
+        let index = cumulative_areas
+            .partition_point(|&area| area < target)
+            .min(faces.len() - 1);
+        let sample = sample_face(&faces[index], rng);
+        if grid.is_free(&sample, radius) {
+            grid.insert(sample, radius);
+            samples.push((index, sample));
+        }
+    }
+    samples
+}
+
+struct SampleGrid {
+    cell_size: f64,
+    cells: HashMap<(i64, i64, i64), Vec<(Point, f64)>>,
+    len: usize,
+    max_radius: f64,
+}
+
+impl SampleGrid {
+    fn new(cell_size: f64) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            len: 0,
+            max_radius: 0.0,
+        }
+    }
+
+    fn cell(&self, point: &Point) -> (i64, i64, i64) {
+        (
+            (point.x / self.cell_size).floor() as i64,
+            (point.y / self.cell_size).floor() as i64,
+            (point.z / self.cell_size).floor() as i64,
+        )
+    }
+
+    fn is_free(&self, point: &Point, radius: f64) -> bool {
+        let conflicts = |(other, other_radius): &(Point, f64)| {
+            let min_radius = radius.min(*other_radius);
+            other.dist(point) < min_radius * min_radius
+        };
+
+        let reach = (radius.min(self.max_radius) / self.cell_size).ceil() as i64;
+        if (2 * reach + 1).pow(3) as usize > self.len {
+            return !self.cells.values().flatten().any(conflicts);
+        }
+
+        let (cx, cy, cz) = self.cell(point);
+        !(-reach..=reach)
+            .flat_map(|dx| {
+                (-reach..=reach).flat_map(move |dy| (-reach..=reach).map(move |dz| (dx, dy, dz)))
+            })
+            .filter_map(|(dx, dy, dz)| self.cells.get(&(cx + dx, cy + dy, cz + dz)))
+            .flatten()
+            .any(conflicts)
+    }
+
+    fn insert(&mut self, point: Point, radius: f64) {
+        let cell = self.cell(&point);
+        self.cells.entry(cell).or_default().push((point, radius));
+        self.len += 1;
+        self.max_radius = self.max_radius.max(radius);
+    }
+}
+
+// the vertices and points along the edges of the faces, so samples keep their distance from the
+// boundary of the face they are inserted into and never form slivers with it
+fn seed(grid: &mut SampleGrid, faces: &[Face], radius: impl Fn(&Point) -> f64) {
+    let mut edges = HashSet::new();
+    for Face { p1, p2, p3 } in faces.iter() {
+        for &(a, b) in [(*p1, *p2), (*p2, *p3), (*p3, *p1)].iter() {
+            if !edges.insert(edge_key(&a, &b)) {
+                continue;
+            }
+            let length = a.distance(&b);
+            let mut t = 0.0;
+            while t < length {
+                let point = a + (b - a) * (t / length);
+                let r = radius(&point);
+                grid.insert(point, r);
+                t += (r / 2.0).max(length * 1e-3);
+            }
+        }
+    }
+}
+
+fn candidates<R: Rng + ?Sized>(faces: &[Face], radii: &[f64], rng: &mut R) -> Vec<(usize, Point)> {
+    const CANDIDATES_PER_DISK: f64 = 10.0;
+    const MAX_CANDIDATES_PER_FACE: f64 = 10_000.0;
+
+    faces
+        .iter()
+        .zip(radii)
+        .enumerate()
+        .flat_map(|(i, (face, r))| {
+            let count = (CANDIDATES_PER_DISK * face.area() / (PI * r * r))
+                .ceil()
+                .min(MAX_CANDIDATES_PER_FACE) as usize;
+            (0..count)
+                .map(|_| (i, sample_face(face, rng)))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+pub fn poisson_disk_samples<R: Rng + ?Sized>(
+    faces: &[Face],
+    radius: f64,
+    rng: &mut R,
+) -> Vec<(usize, Point)> {
+    let mut grid = SampleGrid::new(radius);
+    seed(&mut grid, faces, |_| radius);
+    let mut samples = Vec::new();
+
+    for (face, candidate) in candidates(faces, &vec![radius; faces.len()], rng) {
+        if grid.is_free(&candidate, radius) {
+            grid.insert(candidate, radius);
+            samples.push((face, candidate));
+        }
+    }
+
+    samples
+}
+
+pub fn medial_axis_estimate(points: &[Point], faces: &[Face]) -> Vec<Point> {
+    delaunay(points)
+        .into_iter()
+        .map(|tetra| tetra.circumcenter)
+        .filter(|center| point_inside_shape(center, faces))
+        .collect()
+}
+
+pub fn local_feature_size(point: &Point, medial_axis: &[Point]) -> f64 {
+    medial_axis
+        .iter()
+        .map(|center| center.dist(point))
+        .fold(f64::INFINITY, f64::min)
+        .sqrt()
+}
+
+pub fn adaptive_samples<R: Rng + ?Sized>(
+    points: &[Point],
+    faces: &[Face],
+    epsilon: f64,
+    rng: &mut R,
+) -> Vec<(usize, Point)> {
+    if !(epsilon > 0.0 && epsilon.is_finite()) || faces.is_empty() {
+        return Vec::new();
+    }
+    let medial_axis = medial_axis_estimate(points, faces);
+    if medial_axis.is_empty() {
+        return Vec::new();
+    }
+
+    let radius = |point: &Point| epsilon * local_feature_size(point, &medial_axis);
+
+    let face_radii = faces
+        .iter()
+        .map(|face| radius(&face.centroid()))
+        .collect::<Vec<_>>();
+    let mut sorted_radii = face_radii.clone();
+    sorted_radii.sort_by(f64::total_cmp);
+    let cell_size = sorted_radii[sorted_radii.len() / 2].max(f64::MIN_POSITIVE);
+
+    let mut grid = SampleGrid::new(cell_size);
+    seed(&mut grid, faces, radius);
+    let mut samples = Vec::new();
+
+    for (face, candidate) in candidates(faces, &face_radii, rng) {
+        let r = radius(&candidate);
+        if grid.is_free(&candidate, r) {
+            grid.insert(candidate, r);
+            samples.push((face, candidate));
+        }
+    }
+
+    samples
+}
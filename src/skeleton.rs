@@ -0,0 +1,325 @@
+use super::{edge::Edge, face_adjacency, point::Point, tetrahedron::Tetrahedron};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet, VecDeque},
+};
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Skeleton {
+    pub nodes: Vec<Point>,
+    pub radii: Vec<f64>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl Skeleton {
+    pub fn new(tetrahedrons: &[Tetrahedron]) -> Self {
+        let mut skeleton = Self::default();
+        let mut indices = HashMap::new();
+        let mut node = |tetra: &Tetrahedron, skeleton: &mut Self| {
+            *indices.entry(tetra.circumcenter).or_insert_with(|| {
+                skeleton.nodes.push(tetra.circumcenter);
+                skeleton.radii.push(tetra.circumradius.sqrt());
+                skeleton.nodes.len() - 1
+            })
+        };
+
+        for tetra in tetrahedrons {
+            node(tetra, &mut skeleton);
+        }
+        for (_, (t1, t2)) in face_adjacency(tetrahedrons) {
+            if let Some(t2) = t2 {
+                let (n1, n2) = (node(&t1, &mut skeleton), node(&t2, &mut skeleton));
+                if n1 != n2 {
+                    skeleton.edges.push((n1.min(n2), n1.max(n2)));
+                }
+            }
+        }
+        skeleton.edges.sort_unstable();
+        skeleton.edges.dedup();
+
+        skeleton
+    }
+
+    pub fn segments(&self) -> Vec<Edge> {
+        self.edges
+            .iter()
+            .map(|&(a, b)| Edge::new(self.nodes[a], self.nodes[b]))
+            .collect()
+    }
+
+    pub fn neighbours(&self) -> Vec<Vec<usize>> {
+        let mut neighbours = vec![Vec::new(); self.nodes.len()];
+        for &(a, b) in self.edges.iter() {
+            neighbours[a].push(b);
+            neighbours[b].push(a);
+        }
+        neighbours
+    }
+
+    pub fn branches(&self) -> Vec<Vec<usize>> {
+        let neighbours = self.neighbours();
+        let is_joint = |node: usize| neighbours[node].len() != 2;
+        let edge = |a: usize, b: usize| (a.min(b), a.max(b));
+
+        let mut visited = HashSet::new();
+        let mut branches = Vec::new();
+        let walk = |start: usize, next: usize, visited: &mut HashSet<_>| {
+            let mut branch = vec![start];
+            let (mut previous, mut current) = (start, next);
+            visited.insert(edge(previous, current));
+            loop {
+                branch.push(current);
+                if is_joint(current) || current == start {
+                    break;
+                }
+                let following = *neighbours[current]
+                    .iter()
+                    .find(|&&n| n != previous)
+                    .unwrap();
+                if !visited.insert(edge(current, following)) {
+                    break;
+                }
+                previous = current;
+                current = following;
+            }
+            branch
+        };
+
+        for start in (0..self.nodes.len()).filter(|&n| is_joint(n)) {
+            for &next in neighbours[start].iter() {
+                if !visited.contains(&edge(start, next)) {
+                    branches.push(walk(start, next, &mut visited));
+                }
+            }
+        }
+        for &(a, b) in self.edges.iter() {
+            if !visited.contains(&edge(a, b)) {
+                branches.push(walk(a, b, &mut visited));
+            }
+        }
+
+        branches
+    }
+
+    pub fn branch_length(&self, branch: &[usize]) -> f64 {
+        branch
+            .windows(2)
+            .map(|pair| self.nodes[pair[0]].dist(&self.nodes[pair[1]]).sqrt())
+            .sum()
+    }
+
+    pub fn pruned(&self, min_branch_length: f64) -> Self {
+        let mut skeleton = self.clone();
+        loop {
+            let neighbours = skeleton.neighbours();
+            let removed = skeleton
+                .branches()
+                .into_iter()
+                .filter(|branch| {
+                    let (first, last) = (branch[0], branch[branch.len() - 1]);
+                    let (a, b) = (neighbours[first].len(), neighbours[last].len());
+                    ((a == 1 && b >= 3) || (b == 1 && a >= 3))
+                        && skeleton.branch_length(branch) < min_branch_length
+                })
+                .flat_map(|branch| {
+                    branch
+                        .windows(2)
+                        .map(|pair| (pair[0].min(pair[1]), pair[0].max(pair[1])))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<HashSet<_>>();
+
+            if removed.is_empty() {
+                return skeleton.compacted();
+            }
+            skeleton
+                .edges
+                .retain(|&(a, b)| !removed.contains(&(a.min(b), a.max(b))));
+        }
+    }
+
+    // the tree through the widest balls, with the leaf branches whose tip stays within the
+    // diameter of the ball they leave from removed one at a time, shortest first, until only the
+    // limbs of the shape are left
+    pub fn curve_skeleton(&self) -> Self {
+        self.curve_edges().compacted()
+    }
+
+    fn curve_edges(&self) -> Self {
+        // equally wide edges are ordered by their nodes' radii and positions rather than their
+        // indices, which follow the order the tetrahedrons came in
+        let node = |n: usize| {
+            let point = self.nodes[n];
+            [self.radii[n], point.x, point.y, point.z]
+        };
+        let compare = |a: [f64; 4], b: [f64; 4]| {
+            a.iter()
+                .zip(b.iter())
+                .map(|(x, y)| x.total_cmp(y))
+                .find(|order| order.is_ne())
+                .unwrap_or(Ordering::Equal)
+        };
+        let key = |&(a, b): &(usize, usize)| {
+            let (a, b) = (node(a), node(b));
+            if compare(a, b).is_le() {
+                (a, b)
+            } else {
+                (b, a)
+            }
+        };
+        let mut edges = self.edges.clone();
+        edges.sort_by(|a, b| {
+            let (a, b) = (key(a), key(b));
+            compare(b.0, a.0).then_with(|| compare(b.1, a.1))
+        });
+
+        let mut sets = (0..self.nodes.len()).collect::<Vec<_>>();
+        let root = |mut node: usize, sets: &mut Vec<usize>| {
+            while sets[node] != node {
+                sets[node] = sets[sets[node]];
+                node = sets[node];
+            }
+            node
+        };
+        let mut skeleton = Self {
+            edges: Vec::new(),
+            ..self.clone()
+        };
+        for (a, b) in edges {
+            let (ra, rb) = (root(a, &mut sets), root(b, &mut sets));
+            if ra != rb {
+                sets[ra] = rb;
+                skeleton.edges.push((a, b));
+            }
+        }
+
+        loop {
+            let neighbours = skeleton.neighbours();
+            let shortest = skeleton
+                .branches()
+                .into_iter()
+                .filter_map(|branch| {
+                    let (first, last) = (branch[0], branch[branch.len() - 1]);
+                    let (junction, tip) = match (neighbours[first].len(), neighbours[last].len()) {
+                        (1, n) if n >= 3 => (last, first),
+                        (n, 1) if n >= 3 => (first, last),
+                        _ => return None,
+                    };
+                    let reach = skeleton.nodes[junction].distance(&skeleton.nodes[tip]);
+                    (reach < 2.0 * skeleton.radii[junction]).then_some((reach, branch))
+                })
+                .min_by(|(a, _), (b, _)| a.total_cmp(b));
+
+            let branch = match shortest {
+                Some((_, branch)) => branch,
+                None => return skeleton,
+            };
+            let removed = branch
+                .windows(2)
+                .map(|pair| (pair[0].min(pair[1]), pair[0].max(pair[1])))
+                .collect::<HashSet<_>>();
+            skeleton
+                .edges
+                .retain(|&(a, b)| !removed.contains(&(a.min(b), a.max(b))));
+        }
+    }
+
+    fn compacted(&self) -> Self {
+        let mut indices = vec![None; self.nodes.len()];
+        let mut skeleton = Self::default();
+        for &(a, b) in self.edges.iter() {
+            for &node in [a, b].iter() {
+                if indices[node].is_none() {
+                    indices[node] = Some(skeleton.nodes.len());
+                    skeleton.nodes.push(self.nodes[node]);
+                    skeleton.radii.push(self.radii[node]);
+                }
+            }
+            // renumbering must keep the smaller index first, as edges are looked up that way
+            let (a, b) = (indices[a].unwrap(), indices[b].unwrap());
+            skeleton.edges.push((a.min(b), a.max(b)));
+        }
+        skeleton
+    }
+
+    pub fn armature(&self) -> Armature {
+        let mut joint_of = HashMap::new();
+        let mut joints = Vec::new();
+        let mut links = HashMap::<usize, Vec<usize>>::new();
+        for branch in self.curve_edges().branches() {
+            let (first, last) = (branch[0], branch[branch.len() - 1]);
+            if first == last {
+                continue;
+            }
+            links.entry(first).or_default().push(last);
+            links.entry(last).or_default().push(first);
+        }
+
+        let mut roots = links.keys().copied().collect::<Vec<_>>();
+        roots.sort_by(|&a, &b| self.radii[b].partial_cmp(&self.radii[a]).unwrap());
+
+        for root in roots {
+            if joint_of.contains_key(&root) {
+                continue;
+            }
+            joint_of.insert(root, joints.len());
+            joints.push(Joint {
+                position: self.nodes[root],
+                parent: None,
+                node: root,
+            });
+
+            let mut queue = VecDeque::from(vec![root]);
+            while let Some(current) = queue.pop_front() {
+                for &next in links[&current].iter() {
+                    if joint_of.contains_key(&next) {
+                        continue;
+                    }
+                    joint_of.insert(next, joints.len());
+                    joints.push(Joint {
+                        position: self.nodes[next],
+                        parent: Some(joint_of[&current]),
+                        node: next,
+                    });
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        Armature { joints }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Joint {
+    pub position: Point,
+    pub parent: Option<usize>,
+    pub node: usize,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Armature {
+    pub joints: Vec<Joint>,
+}
+
+impl Armature {
+    pub fn bones(&self) -> Vec<(usize, usize)> {
+        self.joints
+            .iter()
+            .enumerate()
+            .filter_map(|(i, joint)| joint.parent.map(|parent| (parent, i)))
+            .collect()
+    }
+
+    pub fn children(&self, joint: usize) -> Vec<usize> {
+        self.joints
+            .iter()
+            .enumerate()
+            .filter(|(_, other)| other.parent == Some(joint))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
@@ -0,0 +1,116 @@
+use super::{face::Face, point::Point, skeleton::Armature};
+
+pub const MAX_INFLUENCES: usize = 4;
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Skin {
+    pub joints: Vec<[usize; MAX_INFLUENCES]>,
+    pub weights: Vec<[f64; MAX_INFLUENCES]>,
+}
+
+// the closest point and how far along the segment it lies
+fn closest_point_on_segment(point: &Point, a: &Point, b: &Point) -> (Point, f64) {
+    let ab = *b - *a;
+    let length = ab.norm();
+    if length == 0.0 {
+        return (*a, 0.0);
+    }
+    let t = ((*point - *a).dot(&ab) / length).clamp(0.0, 1.0);
+    (*a + ab * t, t)
+}
+
+fn segment_hits_face(from: &Point, to: &Point, face: &Face) -> bool {
+    const EPSILON: f64 = 1e-9;
+
+    let direction = *to - *from;
+    let e1 = face.p2 - face.p1;
+    let e2 = face.p3 - face.p1;
+    let p = direction.cross(&e2);
+    let determinant = e1.dot(&p);
+    if determinant.abs() < EPSILON {
+        return false;
+    }
+
+    let s = *from - face.p1;
+    let u = s.dot(&p) / determinant;
+    if !(0.0..=1.0).contains(&u) {
+        return false;
+    }
+    let q = s.cross(&e1);
+    let v = direction.dot(&q) / determinant;
+    if v < 0.0 || u + v > 1.0 {
+        return false;
+    }
+    let t = e2.dot(&q) / determinant;
+    t > 1e-6 && t < 1.0 - 1e-6
+}
+
+fn visible(from: &Point, to: &Point, faces: &[Face]) -> bool {
+    faces
+        .iter()
+        .filter(|face| face.p1 != *from && face.p2 != *from && face.p3 != *from)
+        .all(|face| !segment_hits_face(from, to, face))
+}
+
+// Every vertex is weighted by the inverse squared distance to the nearest bones it can see
+// without crossing the surface, or to the nearest bone when it sees none. The weight of a bone is
+// shared between its two joints by how far along it the vertex lies, so leaf joints are weighted
+// too. The weights of a vertex sum to one unless the armature has no joints.
+pub fn skinning_weights(points: &[Point], faces: &[Face], armature: &Armature) -> Skin {
+    let mut bones = armature.bones();
+    if bones.is_empty() {
+        bones = (0..armature.joints.len()).map(|i| (i, i)).collect();
+    }
+    let mut skin = Skin::default();
+
+    for point in points {
+        let mut candidates = bones
+            .iter()
+            .map(|&(parent, child)| {
+                let (closest, t) = closest_point_on_segment(
+                    point,
+                    &armature.joints[parent].position,
+                    &armature.joints[child].position,
+                );
+                (parent, child, t, closest, point.dist(&closest))
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|a, b| a.4.total_cmp(&b.4));
+
+        let mut seen = candidates
+            .iter()
+            .filter(|(_, _, _, closest, _)| visible(point, closest, faces))
+            .take(MAX_INFLUENCES)
+            .collect::<Vec<_>>();
+        if seen.is_empty() {
+            seen.extend(candidates.first());
+        }
+
+        let mut influences = Vec::<(usize, f64)>::new();
+        for &&(parent, child, t, _, distance) in seen.iter() {
+            let weight = 1.0 / distance.max(1e-12);
+            for &(joint, share) in [(parent, 1.0 - t), (child, t)].iter() {
+                match influences.iter_mut().find(|(other, _)| *other == joint) {
+                    Some((_, total)) => *total += weight * share,
+                    None => influences.push((joint, weight * share)),
+                }
+            }
+        }
+        influences.sort_by(|a, b| b.1.total_cmp(&a.1));
+        influences.retain(|&(_, weight)| weight > 0.0);
+        influences.truncate(MAX_INFLUENCES);
+
+        let total = influences.iter().map(|&(_, weight)| weight).sum::<f64>();
+        let mut joints = [0; MAX_INFLUENCES];
+        let mut weights = [0.0; MAX_INFLUENCES];
+        for (i, &(joint, weight)) in influences.iter().enumerate() {
+            joints[i] = joint;
+            weights[i] = weight / total;
+        }
+        skin.joints.push(joints);
+        skin.weights.push(weights);
+    }
+
+    skin
+}
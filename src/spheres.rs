@@ -0,0 +1,72 @@
+use super::{point::Point, tetrahedron::Tetrahedron};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere {
+    pub center: Point,
+    pub radius: f64,
+}
+
+impl Sphere {
+    pub fn new(center: Point, radius: f64) -> Self {
+        Self { center, radius }
+    }
+
+    pub fn covers(&self, point: &Point, tolerance: f64) -> bool {
+        let reach = self.radius + tolerance;
+        self.center.dist(point) <= reach * reach
+    }
+}
+
+pub fn medial_balls(tetrahedrons: &[Tetrahedron]) -> Vec<Sphere> {
+    tetrahedrons
+        .iter()
+        .map(|tetra| Sphere::new(tetra.circumcenter, tetra.circumradius.sqrt()))
+        .collect()
+}
+
+pub fn select_spheres(
+    balls: &[Sphere],
+    surface: &[Point],
+    target_count: usize,
+    max_error: f64,
+) -> Vec<Sphere> {
+    let coverage = balls
+        .iter()
+        .map(|ball| {
+            surface
+                .iter()
+                .enumerate()
+                .filter(|(_, point)| ball.covers(point, max_error))
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut covered = vec![false; surface.len()];
+    let mut used = vec![false; balls.len()];
+    let mut selected = Vec::new();
+
+    while selected.len() < target_count {
+        let best = (0..balls.len())
+            .filter(|&i| !used[i])
+            .map(|i| (i, coverage[i].iter().filter(|&&s| !covered[s]).count()))
+            .max_by(|(i, a), (j, b)| {
+                a.cmp(b)
+                    .then(balls[*i].radius.partial_cmp(&balls[*j].radius).unwrap())
+            });
+
+        match best {
+            Some((i, gain)) if gain > 0 => {
+                used[i] = true;
+                for &s in coverage[i].iter() {
+                    covered[s] = true;
+                }
+                selected.push(balls[i]);
+            }
+            _ => break,
+        }
+    }
+
+    selected
+}
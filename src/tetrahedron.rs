@@ -1,60 +1,26 @@
-use super::point::Point;
-use raylib::{
-    color::Color,
-    drawing::{RaylibDraw3D, RaylibDrawHandle, RaylibMode3D},
-    math::Vector3,
-};
-
-#[derive(Copy, Clone)]
-pub struct Tetrahedron {
-    pub p1: Point,
-    pub p2: Point,
-    pub p3: Point,
-    pub p4: Point,
-    pub circumcenter: Point,
-    pub circumradius: f64,
+use super::{face::Face, point::Point, scalar::Scalar};
+use std::cmp::Ordering;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tetrahedron<T = f64> {
+    pub p1: Point<T>,
+    pub p2: Point<T>,
+    pub p3: Point<T>,
+    pub p4: Point<T>,
+    pub circumcenter: Point<T>,
+    pub circumradius: T,
 }
 
-impl Tetrahedron {
-    pub fn new(p1: Point, p2: Point, p3: Point, p4: Point) -> Self {
-        #[rustfmt::skip]
-        let alpha = nalgebra::Matrix4::new(
-            p1.x, p1.y, p1.z, 1.0,
-            p2.x, p2.y, p2.z, 1.0,
-            p3.x, p3.y, p3.z, 1.0,
-            p4.x, p4.y, p4.z, 1.0,
-        )
-        .determinant();
-
-        #[rustfmt::skip]
-        let dx = nalgebra::Matrix4::new(
-            p1.x * p1.x + p1.y * p1.y + p1.z * p1.z, p1.y, p1.z, 1.0,
-            p2.x * p2.x + p2.y * p2.y + p2.z * p2.z, p2.y, p2.z, 1.0,
-            p3.x * p3.x + p3.y * p3.y + p3.z * p3.z, p3.y, p3.z, 1.0,
-            p4.x * p4.x + p4.y * p4.y + p4.z * p4.z, p4.y, p4.z, 1.0,
-        )
-        .determinant();
-
-        #[rustfmt::skip]
-        let dy = -nalgebra::Matrix4::new(
-            p1.x * p1.x + p1.y * p1.y + p1.z * p1.z, p1.x, p1.z, 1.0,
-            p2.x * p2.x + p2.y * p2.y + p2.z * p2.z, p2.x, p2.z, 1.0,
-            p3.x * p3.x + p3.y * p3.y + p3.z * p3.z, p3.x, p3.z, 1.0,
-            p4.x * p4.x + p4.y * p4.y + p4.z * p4.z, p4.x, p4.z, 1.0,
-        )
-        .determinant();
-
-        #[rustfmt::skip]
-        let dz = nalgebra::Matrix4::new(
-            p1.x * p1.x + p1.y * p1.y + p1.z * p1.z, p1.x, p1.y, 1.0,
-            p2.x * p2.x + p2.y * p2.y + p2.z * p2.z, p2.x, p2.y, 1.0,
-            p3.x * p3.x + p3.y * p3.y + p3.z * p3.z, p3.x, p3.y, 1.0,
-            p4.x * p4.x + p4.y * p4.y + p4.z * p4.z, p4.x, p4.y, 1.0,
-        )
-        .determinant();
+impl<T: Scalar> Tetrahedron<T> {
+    pub fn new(p1: Point<T>, p2: Point<T>, p3: Point<T>, p4: Point<T>) -> Self {
+        let (a, b, c) = (p2 - p1, p3 - p1, p4 - p1);
+        let (bc, ca, ab) = (b.cross(&c), c.cross(&a), a.cross(&b));
+        let alpha = T::from_f64(2.0) * a.dot(&bc);
 
-        let circumcenter = Point::new(dx / (2.0 * alpha), dy / (2.0 * alpha), dz / (2.0 * alpha));
-        let circumradius = p1.dist(&circumcenter);
+        let offset = (bc * a.norm() + ca * b.norm() + ab * c.norm()) / alpha;
+        let circumcenter = p1 + offset;
+        let circumradius = offset.norm();
 
         Self {
             p1,
@@ -66,60 +32,135 @@ impl Tetrahedron {
         }
     }
 
-    pub fn has_point(&self, point: &Point) -> bool {
+    pub fn has_point(&self, point: &Point<T>) -> bool {
         (self.p1 == *point) || (self.p2 == *point) || (self.p3 == *point) || (self.p4 == *point)
     }
 
-    pub fn has_point_circumcircle(&self, point: &Point) -> bool {
+    pub fn has_point_circumcircle(&self, point: &Point<T>) -> bool {
         point.dist(&self.circumcenter) <= self.circumradius
     }
 
-    pub fn centroid(&self) -> Point {
-        Point::new(
-            (self.p1.x + self.p2.x + self.p3.x + self.p4.x) / 4.0,
-            (self.p1.y + self.p2.y + self.p3.y + self.p4.y) / 4.0,
-            (self.p1.z + self.p2.z + self.p3.z + self.p4.z) / 4.0,
-        )
+    pub fn contains(&self, point: &Point<T>) -> bool {
+        let orient = |a: Point<T>, b: Point<T>, c: Point<T>, d: Point<T>| {
+            (b - a).dot(&(c - a).cross(&(d - a)))
+        };
+        let (p1, p2, p3, p4, point) = (self.p1, self.p2, self.p3, self.p4, *point);
+        let volume = orient(p1, p2, p3, p4);
+        [
+            orient(point, p2, p3, p4),
+            orient(p1, point, p3, p4),
+            orient(p1, p2, point, p4),
+            orient(p1, p2, p3, point),
+        ]
+        .iter()
+        .all(|&orientation| {
+            (orientation * volume).partial_cmp(&T::from_f64(0.0)) != Some(Ordering::Less)
+        })
+    }
+
+    pub fn faces(&self) -> [Face<T>; 4] {
+        [
+            Face::new(self.p1, self.p2, self.p3),
+            Face::new(self.p1, self.p2, self.p4),
+            Face::new(self.p1, self.p3, self.p4),
+            Face::new(self.p2, self.p3, self.p4),
+        ]
+    }
+
+    pub fn edges(&self) -> [(Point<T>, Point<T>); 6] {
+        [
+            (self.p1, self.p2),
+            (self.p1, self.p3),
+            (self.p1, self.p4),
+            (self.p2, self.p3),
+            (self.p2, self.p4),
+            (self.p3, self.p4),
+        ]
+    }
+
+    pub fn radius_edge_ratio(&self) -> T {
+        let shortest =
+            self.edges()
+                .iter()
+                .map(|(a, b)| a.dist(b))
+                .fold(T::infinity(), |shortest, length| {
+                    if length < shortest {
+                        length
+                    } else {
+                        shortest
+                    }
+                });
+        (self.circumradius / shortest).sqrt()
+    }
+
+    pub fn signed_volume(&self) -> T {
+        (self.p2 - self.p1).dot(&(self.p3 - self.p1).cross(&(self.p4 - self.p1))) / T::from_f64(6.0)
+    }
+
+    pub fn volume(&self) -> T {
+        self.signed_volume().abs()
     }
 
-    pub fn draw(
-        &self,
-        draw_handle: &mut RaylibMode3D<RaylibDrawHandle>,
-        color: Color,
-        outline: bool,
-    ) {
-        let fade = 0.3;
-        if outline {
-            draw_handle.draw_line_3D(Vector3::from(self.p1), Vector3::from(self.p2), color);
-            draw_handle.draw_line_3D(Vector3::from(self.p2), Vector3::from(self.p3), color);
-            draw_handle.draw_line_3D(Vector3::from(self.p3), Vector3::from(self.p1), color);
-            draw_handle.draw_line_3D(Vector3::from(self.p4), Vector3::from(self.p1), color);
-            draw_handle.draw_line_3D(Vector3::from(self.p4), Vector3::from(self.p2), color);
-            draw_handle.draw_line_3D(Vector3::from(self.p4), Vector3::from(self.p3), color);
+    pub fn face_areas(&self) -> [T; 4] {
+        let [f1, f2, f3, f4] = self.faces();
+        [f1.area(), f2.area(), f3.area(), f4.area()]
+    }
+
+    pub fn face_normals(&self) -> [Point<T>; 4] {
+        let opposite = [self.p4, self.p3, self.p2, self.p1];
+        let mut normals = [Point::new(T::from_f64(0.0), T::from_f64(0.0), T::from_f64(0.0)); 4];
+        for (i, face) in self.faces().iter().enumerate() {
+            let normal = face.normal();
+            let normal = normal / normal.length();
+            normals[i] = if normal.dot(&(opposite[i] - face.p1)) > T::from_f64(0.0) {
+                -normal
+            } else {
+                normal
+            };
+        }
+        normals
+    }
+
+    pub fn inradius(&self) -> T {
+        let [a1, a2, a3, a4] = self.face_areas();
+        T::from_f64(3.0) * self.volume() / (a1 + a2 + a3 + a4)
+    }
+
+    pub fn aspect_ratio(&self) -> T {
+        self.circumradius.sqrt() / (T::from_f64(3.0) * self.inradius())
+    }
+
+    pub fn dihedral_angles(&self) -> [f64; 6] {
+        const EDGE_FACES: [(usize, usize); 6] = [(0, 1), (0, 2), (1, 2), (0, 3), (1, 3), (2, 3)];
+        let normals = self.face_normals();
+        let mut angles = [0.0; 6];
+        for (angle, &(a, b)) in angles.iter_mut().zip(EDGE_FACES.iter()) {
+            let cos = normals[a].dot(&normals[b]).to_f64().clamp(-1.0, 1.0);
+            *angle = std::f64::consts::PI - cos.acos();
+        }
+        angles
+    }
+
+    pub fn solid_angles(&self) -> [f64; 4] {
+        let points = [self.p1, self.p2, self.p3, self.p4];
+        let mut angles = [0.0; 4];
+        for (i, angle) in angles.iter_mut().enumerate() {
+            let others = (0..4).filter(|&j| j != i).collect::<Vec<_>>();
+            let [a, b, c] = [others[0], others[1], others[2]].map(|j| points[j] - points[i]);
+            let (la, lb, lc) = (a.length(), b.length(), c.length());
+            let numerator = a.dot(&b.cross(&c)).abs();
+            let denominator = la * lb * lc + a.dot(&b) * lc + a.dot(&c) * lb + b.dot(&c) * la;
+            *angle = 2.0 * numerator.to_f64().atan2(denominator.to_f64());
         }
-        draw_handle.draw_triangle3D(
-            Vector3::from(self.p1),
-            Vector3::from(self.p3),
-            Vector3::from(self.p2),
-            color.fade(fade),
-        );
-        draw_handle.draw_triangle3D(
-            Vector3::from(self.p1),
-            Vector3::from(self.p2),
-            Vector3::from(self.p4),
-            color.fade(fade),
-        );
-        draw_handle.draw_triangle3D(
-            Vector3::from(self.p3),
-            Vector3::from(self.p1),
-            Vector3::from(self.p4),
-            color.fade(fade),
-        );
-        draw_handle.draw_triangle3D(
-            Vector3::from(self.p4),
-            Vector3::from(self.p2),
-            Vector3::from(self.p3),
-            color.fade(fade),
-        );
+        angles
+    }
+
+    pub fn centroid(&self) -> Point<T> {
+        let four = T::from_f64(4.0);
+        Point::new(
+            (self.p1.x + self.p2.x + self.p3.x + self.p4.x) / four,
+            (self.p1.y + self.p2.y + self.p3.y + self.p4.y) / four,
+            (self.p1.z + self.p2.z + self.p3.z + self.p4.z) / four,
+        )
     }
 }
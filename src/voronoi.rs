@@ -0,0 +1,302 @@
+use super::{
+    constrained::{constrained_delaunay, ConstrainedError},
+    face::Face,
+    face_adjacency,
+    point::Point,
+    tetrahedron::Tetrahedron,
+};
+use std::collections::{BTreeSet, HashMap};
+
+#[derive(Copy, Clone, Debug)]
+pub enum Bounds<'a> {
+    Box { min: Point, max: Point },
+    Shape(&'a [Face]),
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VoronoiCell {
+    pub site: Point,
+    pub faces: Vec<Vec<Point>>,
+    pub neighbours: Vec<usize>,
+    pub volume: f64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Side {
+    Neighbour(usize),
+    Bounds,
+    Internal,
+}
+
+type Polyhedron = Vec<(Vec<Point>, Side)>;
+
+struct Plane {
+    normal: Point,
+    offset: f64,
+}
+
+impl Plane {
+    fn new(normal: Point, through: &Point) -> Self {
+        let normal = normal / normal.length();
+        Self {
+            offset: normal.dot(through),
+            normal,
+        }
+    }
+
+    fn distance(&self, point: &Point) -> f64 {
+        self.normal.dot(point) - self.offset
+    }
+}
+
+fn centroid(points: &[Point]) -> Point {
+    points
+        .iter()
+        .fold(Point::new(0.0, 0.0, 0.0), |sum, &point| sum + point)
+        / points.len() as f64
+}
+
+fn cuboid(min: &Point, max: &Point) -> Polyhedron {
+    let corner = |i: usize| {
+        Point::new(
+            if i & 1 == 0 { min.x } else { max.x },
+            if i & 2 == 0 { min.y } else { max.y },
+            if i & 4 == 0 { min.z } else { max.z },
+        )
+    };
+    [
+        [0, 4, 6, 2],
+        [1, 3, 7, 5],
+        [0, 1, 5, 4],
+        [2, 6, 7, 3],
+        [0, 2, 3, 1],
+        [4, 5, 7, 6],
+    ]
+    .iter()
+    .map(|quad| (quad.iter().map(|&i| corner(i)).collect(), Side::Bounds))
+    .collect()
+}
+
+// keeps the part of a convex polyhedron below the plane, closing the cut with a polygon on it
+fn clip(polyhedron: &Polyhedron, plane: &Plane, side: Side, epsilon: f64) -> Polyhedron {
+    let mut result = Vec::new();
+    let mut cap = Vec::<Point>::new();
+    let mut cut = false;
+
+    for (polygon, tag) in polyhedron.iter() {
+        let mut clipped = Vec::new();
+        for (i, a) in polygon.iter().enumerate() {
+            let b = polygon[(i + 1) % polygon.len()];
+            let (da, db) = (plane.distance(a), plane.distance(&b));
+            if da <= epsilon {
+                clipped.push(*a);
+            } else {
+                cut = true;
+            }
+            if da.abs() <= epsilon {
+                cap.push(*a);
+            }
+            if (da < -epsilon && db > epsilon) || (da > epsilon && db < -epsilon) {
+                let point = *a + (b - *a) * (da / (da - db));
+                clipped.push(point);
+                cap.push(point);
+            }
+        }
+        if clipped.len() >= 3 {
+            result.push((clipped, *tag));
+        }
+    }
+
+    let mut unique = Vec::<Point>::new();
+    for point in cap {
+        if unique
+            .iter()
+            .all(|other| other.dist(&point) > epsilon * epsilon)
+        {
+            unique.push(point);
+        }
+    }
+    if cut && unique.len() >= 3 {
+        let center = centroid(&unique);
+        let u = unique[0] - center;
+        let v = plane.normal.cross(&u);
+        let angle = |point: &Point| (*point - center).dot(&v).atan2((*point - center).dot(&u));
+        unique.sort_by(|a, b| angle(a).partial_cmp(&angle(b)).unwrap());
+        result.push((unique, side));
+    }
+    result
+}
+
+fn volume(polyhedron: &Polyhedron) -> f64 {
+    let points = polyhedron
+        .iter()
+        .flat_map(|(polygon, _)| polygon.iter().copied())
+        .collect::<Vec<_>>();
+    if points.is_empty() {
+        return 0.0;
+    }
+    let center = centroid(&points);
+    polyhedron
+        .iter()
+        .flat_map(|(polygon, _)| {
+            (1..polygon.len() - 1).map(move |i| {
+                let (a, b, c) = (
+                    polygon[0] - center,
+                    polygon[i] - center,
+                    polygon[i + 1] - center,
+                );
+                a.dot(&b.cross(&c)).abs() / 6.0
+            })
+        })
+        .sum()
+}
+
+fn bounding_box(points: impl Iterator<Item = Point>) -> (Point, Point) {
+    let mut min = Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+    let mut max = -min;
+    for point in points {
+        min = Point::new(min.x.min(point.x), min.y.min(point.y), min.z.min(point.z));
+        max = Point::new(max.x.max(point.x), max.y.max(point.y), max.z.max(point.z));
+    }
+    (min, max)
+}
+
+// cells are returned for the vertices of the triangulation, in the order they first appear, and
+// clipped to a shape by intersecting them with each tetrahedron of its interior, which fails if the
+// shape's surface cannot be recovered
+pub fn voronoi_cells(
+    tetrahedrons: &[Tetrahedron],
+    bounds: &Bounds,
+) -> Result<Vec<VoronoiCell>, ConstrainedError> {
+    power_cells(tetrahedrons, |_| 0.0, bounds)
+}
+
+pub(crate) fn power_cells(
+    tetrahedrons: &[Tetrahedron],
+    weight: impl Fn(&Point) -> f64,
+    bounds: &Bounds,
+) -> Result<Vec<VoronoiCell>, ConstrainedError> {
+    let mut index = HashMap::new();
+    let mut sites = Vec::new();
+    let mut neighbours = Vec::<BTreeSet<usize>>::new();
+    for tetra in tetrahedrons.iter() {
+        for point in [tetra.p1, tetra.p2, tetra.p3, tetra.p4].iter() {
+            index.entry(*point).or_insert_with(|| {
+                sites.push(*point);
+                neighbours.push(BTreeSet::new());
+                sites.len() - 1
+            });
+        }
+        for (a, b) in tetra.edges().iter() {
+            let (a, b) = (index[a], index[b]);
+            neighbours[a].insert(b);
+            neighbours[b].insert(a);
+        }
+    }
+
+    let (min, max, interior) = match *bounds {
+        Bounds::Box { min, max } => (min, max, None),
+        Bounds::Shape(faces) => {
+            let points = faces
+                .iter()
+                .flat_map(|face| [face.p1, face.p2, face.p3])
+                .collect::<Vec<_>>();
+            let (min, max) = bounding_box(points.iter().copied());
+            let interior = constrained_delaunay(&points, faces, 10 * points.len())?.interior();
+            (min, max, Some(interior))
+        }
+    };
+    let epsilon = 1e-9 * min.distance(&max);
+
+    // every face of the shape's tetrahedra as a plane facing out, and whether it is on the surface
+    let pieces = interior.map(|interior| {
+        let adjacency = face_adjacency(&interior);
+        interior
+            .iter()
+            .map(|tetra| {
+                let opposite = [tetra.p4, tetra.p3, tetra.p2, tetra.p1];
+                let planes = tetra
+                    .faces()
+                    .iter()
+                    .zip(opposite.iter())
+                    .map(|(face, opposite)| {
+                        let normal = face.normal();
+                        let normal = if normal.dot(&(*opposite - face.p1)) > 0.0 {
+                            -normal
+                        } else {
+                            normal
+                        };
+                        let side = match adjacency[face].1 {
+                            Some(_) => Side::Internal,
+                            None => Side::Bounds,
+                        };
+                        (Plane::new(normal, &face.p1), side)
+                    })
+                    .collect::<Vec<_>>();
+                let (min, max) =
+                    bounding_box([tetra.p1, tetra.p2, tetra.p3, tetra.p4].iter().copied());
+                (min, max, planes)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    Ok(sites
+        .iter()
+        .enumerate()
+        .map(|(i, &site)| {
+            let mut cell = cuboid(&min, &max);
+            for &j in neighbours[i].iter() {
+                // the plane of equal power distance, which is the bisector for equal weights
+                let offset = sites[j] - site;
+                let t = 0.5 + (weight(&site) - weight(&sites[j])) / (2.0 * offset.norm());
+                let plane = Plane::new(offset, &(site + offset * t));
+                cell = clip(&cell, &plane, Side::Neighbour(j), epsilon);
+            }
+
+            let cells = match &pieces {
+                None => vec![cell],
+                Some(pieces) => {
+                    let (cell_min, cell_max) =
+                        bounding_box(cell.iter().flat_map(|(polygon, _)| polygon.iter().copied()));
+                    pieces
+                        .iter()
+                        .filter(|(min, max, _)| {
+                            min.x <= cell_max.x
+                                && min.y <= cell_max.y
+                                && min.z <= cell_max.z
+                                && cell_min.x <= max.x
+                                && cell_min.y <= max.y
+                                && cell_min.z <= max.z
+                        })
+                        .map(|(_, _, planes)| {
+                            planes.iter().fold(cell.clone(), |piece, (plane, side)| {
+                                clip(&piece, plane, *side, epsilon)
+                            })
+                        })
+                        .collect()
+                }
+            };
+
+            let mut faces = Vec::new();
+            let mut adjacent = BTreeSet::new();
+            for (polygon, side) in cells.iter().flatten() {
+                match side {
+                    Side::Internal => continue,
+                    Side::Neighbour(j) => {
+                        adjacent.insert(*j);
+                    }
+                    Side::Bounds => {}
+                }
+                faces.push(polygon.clone());
+            }
+
+            VoronoiCell {
+                site,
+                faces,
+                neighbours: adjacent.into_iter().collect(),
+                volume: cells.iter().map(volume).sum(),
+            }
+        })
+        .collect())
+}
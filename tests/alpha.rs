@@ -0,0 +1,83 @@
+mod common;
+
+use common::{assert_close, volume};
+use medial_axis_3d::{alpha::AlphaComplex, delaunay, point::Point};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn cloud(center: Point, count: usize, seed: u64) -> Vec<Point> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..count)
+        .map(|_| {
+            center
+                + Point::new(
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                )
+        })
+        .collect()
+}
+
+#[test]
+fn infinite_alpha_is_the_convex_hull() {
+    let tetrahedrons = delaunay(&cloud(Point::new(0.0, 0.0, 0.0), 60, 1));
+    let shape = AlphaComplex::new(&tetrahedrons).shape(f64::INFINITY);
+    assert_eq!(shape.tetrahedrons.len(), tetrahedrons.len());
+    assert_close(volume(&shape.tetrahedrons), volume(&tetrahedrons), 1e-12);
+
+    // a closed boundary, turned outwards
+    let centroid = shape
+        .boundary
+        .iter()
+        .fold(Point::new(0.0, 0.0, 0.0), |sum, face| sum + face.centroid())
+        / shape.boundary.len() as f64;
+    for face in shape.boundary.iter() {
+        assert!(face.normal().dot(&(face.p1 - centroid)) > 0.0);
+    }
+}
+
+#[test]
+fn zero_alpha_is_empty() {
+    let complex = AlphaComplex::new(&delaunay(&cloud(Point::new(0.0, 0.0, 0.0), 60, 2)));
+    let shape = complex.shape(0.0);
+    assert!(shape.tetrahedrons.is_empty());
+    assert!(shape.boundary.is_empty());
+}
+
+#[test]
+fn faces_enter_no_later_than_their_tetrahedra() {
+    let complex = AlphaComplex::new(&delaunay(&cloud(Point::new(0.0, 0.0, 0.0), 60, 3)));
+    for &(tetra, alpha) in complex.tetrahedrons.iter() {
+        for face in tetra.faces().iter() {
+            let (_, face_alpha) = complex.faces.iter().find(|(f, _)| f == face).unwrap();
+            assert!(*face_alpha <= alpha);
+        }
+    }
+    let mut previous = 0;
+    for &alpha in [0.01, 0.1, 0.5, 1.0, 10.0].iter() {
+        let count = complex.shape(alpha).tetrahedrons.len();
+        assert!(count >= previous);
+        previous = count;
+    }
+}
+
+#[test]
+fn separate_clusters_stay_apart_below_their_gap() {
+    let mut points = cloud(Point::new(0.0, 0.0, 0.0), 40, 4);
+    points.extend(cloud(Point::new(20.0, 0.0, 0.0), 40, 5));
+    let complex = AlphaComplex::new(&delaunay(&points));
+    let alpha = complex.covering_alpha();
+    assert!(alpha < 25.0);
+
+    let shape = complex.shape(alpha);
+    for point in points.iter() {
+        assert!(shape
+            .tetrahedrons
+            .iter()
+            .any(|tetra| tetra.has_point(point)));
+    }
+    for tetra in shape.tetrahedrons.iter() {
+        let xs = [tetra.p1.x, tetra.p2.x, tetra.p3.x, tetra.p4.x];
+        assert!(xs.iter().all(|&x| x < 10.0) || xs.iter().all(|&x| x > 10.0));
+    }
+}
@@ -0,0 +1,64 @@
+#![allow(dead_code)]
+
+use medial_axis_3d::{
+    face::Face,
+    io::{load_mesh, Mesh},
+    point::Point,
+    tetrahedron::Tetrahedron,
+};
+
+// an axis-aligned box from the origin to `size`, with its faces turned outwards
+pub fn cuboid(size: Point) -> Mesh {
+    let points = (0..8)
+        .map(|i| {
+            Point::new(
+                if i & 1 == 0 { 0.0 } else { size.x },
+                if i & 2 == 0 { 0.0 } else { size.y },
+                if i & 4 == 0 { 0.0 } else { size.z },
+            )
+        })
+        .collect::<Vec<_>>();
+    let faces = [
+        [0, 2, 3, 1],
+        [4, 5, 7, 6],
+        [0, 1, 5, 4],
+        [2, 6, 7, 3],
+        [0, 4, 6, 2],
+        [1, 3, 7, 5],
+    ]
+    .iter()
+    .flat_map(|&[a, b, c, d]| {
+        [
+            Face::new(points[a], points[b], points[c]),
+            Face::new(points[a], points[c], points[d]),
+        ]
+    })
+    .collect();
+    Mesh { points, faces }
+}
+
+pub fn dino() -> Mesh {
+    load_mesh(concat!(env!("CARGO_MANIFEST_DIR"), "/input/dino.obj")).unwrap()
+}
+
+pub fn surface_volume(faces: &[Face]) -> f64 {
+    faces
+        .iter()
+        .map(|face| face.p1.dot(&face.p2.cross(&face.p3)))
+        .sum::<f64>()
+        / 6.0
+}
+
+pub fn volume(tetrahedrons: &[Tetrahedron]) -> f64 {
+    tetrahedrons.iter().map(Tetrahedron::volume).sum()
+}
+
+pub fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() <= tolerance * expected.abs().max(1.0),
+        "{} is not within {} of {}",
+        actual,
+        tolerance,
+        expected
+    );
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use common::{assert_close, cuboid, dino, surface_volume, volume};
+use medial_axis_3d::{
+    constrained::{constrained_delaunay, ConstrainedError},
+    point::Point,
+};
+
+#[test]
+fn box_interior_fills_the_box() {
+    let mesh = cuboid(Point::new(10.0, 3.0, 1.0));
+    let constrained = constrained_delaunay(&mesh.points, &mesh.faces, 100).unwrap();
+    assert!(constrained.missing_subfaces().is_empty());
+    assert_close(volume(&constrained.interior()), 30.0, 1e-9);
+}
+
+#[test]
+fn dino_interior_matches_surface_volume() {
+    let mesh = dino();
+    let constrained =
+        constrained_delaunay(&mesh.points, &mesh.faces, 10 * mesh.points.len()).unwrap();
+    assert!(constrained.missing_subfaces().is_empty());
+    assert_close(
+        volume(&constrained.interior()),
+        surface_volume(&mesh.faces).abs(),
+        1e-9,
+    );
+}
+
+#[test]
+fn running_out_of_steiner_points_is_an_error() {
+    // the box's cospherical corners triangulate with some face diagonals across its quads
+    let mesh = cuboid(Point::new(10.0, 3.0, 1.0));
+    match constrained_delaunay(&mesh.points, &mesh.faces, 0) {
+        Err(ConstrainedError::SteinerPointLimit {
+            max_steiner_points,
+            missing_subfaces,
+        }) => {
+            assert_eq!(max_steiner_points, 0);
+            assert!(missing_subfaces > 0);
+        }
+        Ok(_) => panic!("the surface was recovered without steiner points"),
+    }
+}
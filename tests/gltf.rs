@@ -0,0 +1,108 @@
+mod common;
+
+use common::cuboid;
+use medial_axis_3d::{
+    face::Face,
+    io::gltf::{self, Document},
+    point::Point,
+    skeleton::Skeleton,
+};
+use serde_json::Value;
+use std::{convert::TryInto, io};
+
+fn gltf(document: &Document) -> Value {
+    let mut bytes = Vec::new();
+    gltf::write_gltf(&mut bytes, document).unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+fn glb(document: &Document) -> (Vec<u8>, Value) {
+    let mut bytes = Vec::new();
+    gltf::write_glb(&mut bytes, document).unwrap();
+    let word = |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+    assert_eq!(&bytes[..4], b"glTF");
+    assert_eq!(word(8) as usize, bytes.len());
+    let json = serde_json::from_slice(&bytes[20..20 + word(12) as usize]).unwrap();
+    (bytes, json)
+}
+
+#[test]
+fn empty_document_has_no_buffers() {
+    let skeleton = Skeleton {
+        nodes: vec![Point::new(0.0, 0.0, 0.0)],
+        radii: vec![1.0],
+        edges: Vec::new(),
+    };
+    for document in [
+        Document::default(),
+        Document {
+            skeleton: Some(&skeleton),
+            ..Document::default()
+        },
+    ]
+    .iter()
+    {
+        let json = gltf(document);
+        for property in ["buffers", "bufferViews", "accessors", "nodes"].iter() {
+            assert!(json.get(property).is_none(), "{}", property);
+        }
+
+        // nothing follows the json chunk
+        let (bytes, json) = glb(document);
+        assert!(json.get("buffers").is_none());
+        let json_length = u32::from_le_bytes(bytes[12..16].try_into().unwrap()) as usize;
+        assert_eq!(bytes.len(), 20 + json_length);
+    }
+}
+
+#[test]
+fn surface_buffers_hold_its_positions_and_indices() {
+    let mesh = cuboid(Point::new(1.0, 2.0, 3.0));
+    let document = Document {
+        faces: &mesh.faces,
+        ..Document::default()
+    };
+    let json = gltf(&document);
+    let positions = &json["accessors"][0];
+    assert_eq!(positions["count"], 8);
+    assert_eq!(positions["max"], serde_json::json!([1, 2, 3]));
+    assert_eq!(json["accessors"][1]["count"], 36);
+    // 8 points of 3 floats and 36 indices, each of 4 bytes
+    assert_eq!(json["buffers"][0]["byteLength"], 8 * 12 + 36 * 4);
+
+    let (bytes, json) = glb(&document);
+    assert_eq!(json["buffers"][0]["byteLength"], 8 * 12 + 36 * 4);
+    assert_eq!(&bytes[bytes.len() - 8 * 12 - 36 * 4 - 4..][..4], b"BIN\0");
+}
+
+#[test]
+fn non_finite_coordinates_are_errors() {
+    let face = |x| {
+        Face::new(
+            Point::new(x, 0.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(0.0, 0.0, 1.0),
+        )
+    };
+    for &x in [f64::NAN, f64::INFINITY, 1e39].iter() {
+        let faces = [face(x)];
+        let document = Document {
+            faces: &faces,
+            ..Document::default()
+        };
+        let error = gltf::write_gltf(&mut Vec::new(), &document).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+        let skeleton = Skeleton {
+            nodes: vec![Point::new(0.0, 0.0, 0.0), Point::new(0.0, x, 0.0)],
+            radii: vec![1.0, 1.0],
+            edges: vec![(0, 1)],
+        };
+        let document = Document {
+            skeleton: Some(&skeleton),
+            ..Document::default()
+        };
+        let error = gltf::write_glb(&mut Vec::new(), &document).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
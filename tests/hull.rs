@@ -0,0 +1,95 @@
+mod common;
+
+use common::{assert_close, cuboid};
+use medial_axis_3d::{
+    hull::{convex_hull, ConvexHull},
+    point::Point,
+    repair,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn random_points(count: usize, scale: Point, seed: u64) -> Vec<Point> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..count)
+        .map(|_| {
+            Point::new(
+                rng.gen_range(0.0..scale.x),
+                rng.gen_range(0.0..scale.y),
+                rng.gen_range(0.0..scale.z),
+            )
+        })
+        .collect()
+}
+
+fn assert_encloses(hull: &ConvexHull, points: &[Point]) {
+    assert!(repair::boundary_loops(&hull.faces).is_empty());
+    for face in hull.faces.iter() {
+        let normal = face.normal() / face.normal().length();
+        for point in points.iter() {
+            assert!(
+                normal.dot(&(*point - face.p1)) <= 1e-9,
+                "{:?} is outside {:?}",
+                point,
+                face
+            );
+        }
+    }
+}
+
+#[test]
+fn random_points_are_on_or_inside_every_facet() {
+    for seed in 0..10 {
+        let points = random_points(60, Point::new(1.0, 1.0, 1.0), seed);
+        assert_encloses(&convex_hull(&points), &points);
+    }
+}
+
+#[test]
+fn flattened_points_are_on_or_inside_every_facet() {
+    for seed in 0..10 {
+        let points = random_points(60, Point::new(1.0, 1.0, 0.05), seed);
+        let hull = convex_hull(&points);
+        assert!(hull.volume() > 0.0);
+        assert_encloses(&hull, &points);
+    }
+}
+
+#[test]
+fn hull_of_a_filled_box_is_the_box() {
+    let mut points = cuboid(Point::new(2.0, 3.0, 4.0)).points;
+    points.extend(
+        random_points(200, Point::new(2.0, 3.0, 4.0), 42)
+            .iter()
+            .map(|&p| p * 0.9 + Point::new(0.1, 0.1, 0.1)),
+    );
+    let hull = convex_hull(&points);
+    assert_eq!(hull.vertices.len(), 8);
+    assert_close(hull.volume(), 24.0, 1e-12);
+    assert_close(hull.area(), 2.0 * (6.0 + 8.0 + 12.0), 1e-12);
+}
+
+#[test]
+fn hull_of_a_sampled_sphere_approaches_the_ball() {
+    let points = (0..2000)
+        .map(|i| {
+            // a fibonacci lattice on the unit sphere
+            let z = 1.0 - (2.0 * i as f64 + 1.0) / 2000.0;
+            let angle = i as f64 * std::f64::consts::PI * (3.0 - 5f64.sqrt());
+            let r = (1.0 - z * z).sqrt();
+            Point::new(r * angle.cos(), r * angle.sin(), z)
+        })
+        .collect::<Vec<_>>();
+    let hull = convex_hull(&points);
+    assert_eq!(hull.vertices.len(), points.len());
+    assert_close(hull.volume(), 4.0 / 3.0 * std::f64::consts::PI, 1e-2);
+    assert_encloses(&hull, &points);
+}
+
+#[test]
+fn coplanar_points_have_no_hull() {
+    let points = random_points(20, Point::new(1.0, 1.0, 1.0), 7)
+        .iter()
+        .map(|p| Point::new(p.x, p.y, 0.5))
+        .collect::<Vec<_>>();
+    assert!(convex_hull(&points).faces.is_empty());
+}
@@ -0,0 +1,125 @@
+mod common;
+
+use common::cuboid;
+use medial_axis_3d::{
+    edge::Edge,
+    face::Face,
+    io::{
+        load_mesh,
+        obj::{self, GroupKind, Obj, ObjError},
+        save_mesh,
+    },
+    point::Point,
+};
+use std::{env, fs, io};
+
+fn read(text: &str) -> Obj {
+    obj::read(text.as_bytes()).unwrap()
+}
+
+fn endpoints(lines: &[Edge]) -> Vec<(Point, Point)> {
+    lines.iter().map(|line| (line.p1, line.p2)).collect()
+}
+
+const TRIANGLE: &str = "v 0 0 0\nv 1 0 0\nv 0 1 0\n";
+
+#[test]
+fn negative_indices_count_back_from_the_last_vertex() {
+    let obj = read(&format!("{}f -3 -2 -1\n", TRIANGLE));
+    assert_eq!(
+        obj.faces,
+        vec![Face::new(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, 1.0, 0.0)
+        )]
+    );
+}
+
+#[test]
+fn texture_and_normal_indices_are_ignored() {
+    let expected = read(&format!("{}f 1 2 3\n", TRIANGLE)).faces;
+    for face in ["f 1/1/1 2/2/2 3/3/3", "f 1//1 2//2 3//3", "f 1/1 2/2 3/3"].iter() {
+        let obj = read(&format!(
+            "{}vt 0 0\nvt 1 0\nvt 0 1\nvn 0 0 1\nvn 0 0 1\nvn 0 0 1\n{}\n",
+            TRIANGLE, face
+        ));
+        assert_eq!(obj.faces, expected, "{}", face);
+    }
+}
+
+#[test]
+fn groups_start_at_the_following_faces_and_lines() {
+    let obj = read(&format!(
+        "{}o first\nf 1 2 3\ng second part\nl 1 2 3\nf 3 2 1\n",
+        TRIANGLE
+    ));
+    assert_eq!(obj.groups.len(), 2);
+    assert_eq!(obj.groups[0].kind, GroupKind::Object);
+    assert_eq!(obj.groups[0].name, "first");
+    assert_eq!((obj.groups[0].first_face, obj.groups[0].first_line), (0, 0));
+    assert_eq!(obj.groups[1].kind, GroupKind::Group);
+    assert_eq!(obj.groups[1].name, "second part");
+    assert_eq!((obj.groups[1].first_face, obj.groups[1].first_line), (1, 0));
+}
+
+#[test]
+fn polylines_become_edges() {
+    let obj = read(&format!("{}l 1 2 3\n", TRIANGLE));
+    assert_eq!(
+        endpoints(&obj.lines),
+        vec![
+            (Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)),
+            (Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0)),
+        ]
+    );
+}
+
+#[test]
+fn out_of_range_index_reports_its_line() {
+    for face in ["f 1 2 4", "f 1 2 -4", "f 0 1 2", "f 1 2 x"].iter() {
+        match obj::read(format!("{}# comment\n{}\n", TRIANGLE, face).as_bytes()) {
+            Err(ObjError::Parse { line, .. }) => assert_eq!(line, 5, "{}", face),
+            result => panic!("{}: expected a parse error, got {:?}", face, result),
+        }
+    }
+}
+
+#[test]
+fn written_obj_reads_back() {
+    let mut original = Obj::from(&cuboid(Point::new(1.0, 2.0, 3.0)));
+    original.lines = vec![Edge::new(
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(1.0, 2.0, 3.0),
+    )];
+
+    let mut bytes = Vec::new();
+    obj::write(&mut bytes, &original).unwrap();
+    let obj = obj::read(bytes.as_slice()).unwrap();
+
+    assert_eq!(obj.points.len(), 8);
+    assert_eq!(obj.faces, original.faces);
+    assert_eq!(endpoints(&obj.lines), endpoints(&original.lines));
+}
+
+#[test]
+fn obj_extension_is_saved_and_loaded() {
+    let path = env::temp_dir().join("medial_axis_3d_obj.OBJ");
+    let mesh = cuboid(Point::new(1.0, 2.0, 3.0));
+    save_mesh(&path, &mesh).unwrap();
+    let loaded = load_mesh(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.faces, mesh.faces);
+}
+
+#[test]
+fn unknown_extension_is_an_error() {
+    let path = env::temp_dir().join("medial_axis_3d_obj.unknown");
+    let error = save_mesh(&path, &cuboid(Point::new(1.0, 1.0, 1.0))).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    assert!(!path.exists());
+
+    fs::write(&path, TRIANGLE).unwrap();
+    assert!(load_mesh(&path).is_err());
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,144 @@
+mod common;
+
+use common::cuboid;
+use medial_axis_3d::{
+    io::ply::{self, Format, Ply},
+    point::Point,
+};
+
+fn sample() -> Ply {
+    let mut ply = Ply::from(&cuboid(Point::new(1.0, 2.0, 3.0)));
+    let radii = (0..ply.points.len()).map(|i| i as f64 / 2.0).collect();
+    ply.properties.push(("radius".to_string(), radii));
+    ply.faces.push(vec![0, 1, 3, 2]);
+    ply.edges = vec![(0, 7), (3, 4)];
+    ply
+}
+
+fn round_trip(ply: &Ply, format: Format) -> Ply {
+    let mut bytes = Vec::new();
+    ply::write(&mut bytes, ply, format).unwrap();
+    ply::parse(&bytes).unwrap()
+}
+
+#[test]
+fn every_format_round_trips() {
+    let ply = sample();
+    for &format in [
+        Format::Ascii,
+        Format::BinaryLittleEndian,
+        Format::BinaryBigEndian,
+    ]
+    .iter()
+    {
+        let read = round_trip(&ply, format);
+        assert_eq!(read.points, ply.points);
+        assert_eq!(read.property("radius"), ply.property("radius"));
+        assert_eq!(read.faces, ply.faces);
+        assert_eq!(read.edges, ply.edges);
+    }
+}
+
+#[test]
+fn face_with_more_vertices_than_a_uchar_count_is_an_error() {
+    let mut ply = sample();
+    ply.points = (0..300)
+        .map(|i| Point::new((i as f64).cos(), (i as f64).sin(), 0.0))
+        .collect();
+    ply.properties.clear();
+    ply.faces = vec![(0..300).collect()];
+    ply.edges.clear();
+
+    let mut bytes = Vec::new();
+    let error = ply::write(&mut bytes, &ply, Format::BinaryLittleEndian).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(bytes.is_empty());
+}
+
+#[test]
+fn property_without_a_value_per_vertex_is_an_error() {
+    let mut ply = sample();
+    ply.properties[0].1.pop();
+
+    let mut bytes = Vec::new();
+    let error = ply::write(&mut bytes, &ply, Format::Ascii).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(bytes.is_empty());
+}
+
+#[test]
+fn index_past_the_vertices_is_an_error() {
+    let mut ply = sample();
+    ply.edges.push((0, ply.points.len()));
+
+    let mut bytes = Vec::new();
+    assert!(ply::write(&mut bytes, &ply, Format::BinaryBigEndian).is_err());
+}
+
+fn ascii(elements: &str, data: &str) -> String {
+    format!(
+        "ply\nformat ascii 1.0\n{}end_header\n0 0 0\n1 0 0\n0 1 0\n{}",
+        elements, data
+    )
+}
+
+const VERTICES: &str = "element vertex 3\nproperty float x\nproperty float y\nproperty float z\n";
+
+#[test]
+fn negative_or_fractional_indices_are_errors() {
+    for (elements, data, message) in [
+        (
+            "element face 1\nproperty list uchar int vertex_indices\n",
+            "3 0 1 -1\n",
+            "face 0: invalid vertex index -1 on line 13",
+        ),
+        (
+            "element face 1\nproperty list uchar float vertex_indices\n",
+            "3 0 1 1.5\n",
+            "face 0: invalid vertex index 1.5 on line 13",
+        ),
+        (
+            "element face 1\nproperty list float int vertex_indices\n",
+            "nan 0 1 2\n",
+            "face 0: invalid list count NaN on line 13",
+        ),
+        (
+            "element edge 1\nproperty int vertex1\nproperty int vertex2\n",
+            "0\n-2\n",
+            "edge 0: invalid vertex index -2 on line 15",
+        ),
+    ]
+    .iter()
+    {
+        let text = ascii(&format!("{}{}", VERTICES, elements), data);
+        let error = ply::parse(text.as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), *message);
+    }
+}
+
+#[test]
+fn negative_index_in_binary_data_is_an_error() {
+    let mut bytes = b"ply\nformat binary_little_endian 1.0\nelement vertex 1\nproperty float x\nproperty float y\nproperty float z\nelement edge 1\nproperty int vertex1\nproperty int vertex2\nend_header\n".to_vec();
+    for value in [0.0f32, 0.0, 0.0].iter() {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    bytes.extend_from_slice(&0i32.to_le_bytes());
+    bytes.extend_from_slice(&(-1i32).to_le_bytes());
+    let error = ply::parse(&bytes).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("edge 0: invalid vertex index -1"));
+}
+
+#[test]
+fn vertices_need_positions() {
+    let text = ascii(
+        "element vertex 3\nproperty float x\nproperty float y\nproperty float nz\n",
+        "",
+    );
+    let error = ply::parse(text.as_bytes()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "header line 7: missing vertex property `z`"
+    );
+}
@@ -0,0 +1,52 @@
+use medial_axis_3d::point::Point;
+
+#[test]
+fn nalgebra_conversions_round_trip() {
+    let point = Point::new(1.0, -2.0, 3.5);
+    let nalgebra_point = nalgebra::Point3::from(point);
+    assert_eq!(nalgebra_point, nalgebra::Point3::new(1.0, -2.0, 3.5));
+    assert_eq!(Point::from(nalgebra_point), point);
+
+    let vector = nalgebra::Vector3::from(point);
+    assert_eq!(vector, nalgebra::Vector3::new(1.0, -2.0, 3.5));
+    assert_eq!(Point::from(vector), point);
+
+    let single = Point::new(1.0f32, -2.0, 3.5);
+    assert_eq!(Point::from(nalgebra::Point3::from(single)), single);
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn mint_conversions_round_trip() {
+    let point = Point::new(1.0, -2.0, 3.5);
+    let mint_point = mint::Point3::from(point);
+    assert_eq!(mint_point, mint::Point3::from([1.0, -2.0, 3.5]));
+    assert_eq!(Point::from(mint_point), point);
+
+    let vector = mint::Vector3::from(point);
+    assert_eq!(vector, mint::Vector3::from([1.0, -2.0, 3.5]));
+    assert_eq!(Point::from(vector), point);
+}
+
+#[test]
+fn length_is_the_square_root_of_the_squared_distance() {
+    let (a, b) = (Point::new(1.0, 2.0, 3.0), Point::new(3.0, 5.0, 9.0));
+    // `dist` and `norm` are squared, `distance` and `length` are not
+    assert_eq!(a.dist(&b), 49.0);
+    assert_eq!(a.distance(&b), 7.0);
+    assert_eq!((b - a).norm(), 49.0);
+    assert_eq!((b - a).length(), 7.0);
+    assert_eq!(a.distance(&b), b.distance(&a));
+}
+
+#[test]
+fn operators_act_componentwise() {
+    let (a, b) = (Point::new(1.0, 2.0, 3.0), Point::new(4.0, 6.0, 8.0));
+    assert_eq!(a + b, Point::new(5.0, 8.0, 11.0));
+    assert_eq!(b - a, Point::new(3.0, 4.0, 5.0));
+    assert_eq!(a * 2.0, Point::new(2.0, 4.0, 6.0));
+    assert_eq!(b / 2.0, Point::new(2.0, 3.0, 4.0));
+    assert_eq!(-a, Point::new(-1.0, -2.0, -3.0));
+    assert_eq!(a.cross(&b), Point::new(-2.0, 4.0, -2.0));
+    assert_eq!(a.cross(&b).dot(&a), 0.0);
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use common::assert_close;
+use medial_axis_3d::{face::Face, io::obj, point::Point, polygon::Polygon};
+
+fn polygon(points: &[(f64, f64, f64)]) -> Polygon {
+    Polygon::new(
+        points
+            .iter()
+            .map(|&(x, y, z)| Point::new(x, y, z))
+            .collect(),
+    )
+}
+
+fn area(faces: &[Face]) -> f64 {
+    faces.iter().map(Face::area).sum()
+}
+
+// every triangle turns the same way as the outline, so with the area preserved none overlap
+fn assert_upward(faces: &[Face]) {
+    for face in faces {
+        assert!(face.normal().z > 1e-9, "{:?} is not turned upwards", face);
+    }
+}
+
+#[test]
+fn convex_polygon_is_split_into_n_minus_two_triangles() {
+    let hexagon = Polygon::new(
+        (0..6)
+            .map(|i| {
+                let angle = i as f64 * std::f64::consts::PI / 3.0;
+                Point::new(angle.cos(), angle.sin(), 0.0)
+            })
+            .collect(),
+    );
+    let faces = hexagon.triangulate().unwrap();
+    assert_eq!(faces.len(), 4);
+    assert_upward(&faces);
+    assert_close(area(&faces), 1.5 * 3f64.sqrt(), 1e-12);
+}
+
+#[test]
+fn concave_polygon_keeps_its_notch_open() {
+    let l_shape = polygon(&[
+        (0.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (2.0, 1.0, 0.0),
+        (1.0, 1.0, 0.0),
+        (1.0, 2.0, 0.0),
+        (0.0, 2.0, 0.0),
+    ]);
+    let faces = l_shape.triangulate().unwrap();
+    assert_eq!(faces.len(), 4);
+    assert_upward(&faces);
+    assert_close(area(&faces), 3.0, 1e-12);
+    for face in faces.iter() {
+        let centroid = face.centroid();
+        assert!(centroid.x < 1.0 || centroid.y < 1.0, "{:?}", face);
+    }
+}
+
+#[test]
+fn non_planar_quad_is_split_along_a_diagonal() {
+    let quad = polygon(&[
+        (0.0, 0.0, 0.0),
+        (1.0, 0.0, 0.0),
+        (1.0, 1.0, 0.2),
+        (0.0, 1.0, 0.0),
+    ]);
+    let faces = quad.triangulate().unwrap();
+    assert_eq!(faces.len(), 2);
+    assert_upward(&faces);
+    for point in quad.points.iter() {
+        assert!(faces
+            .iter()
+            .any(|face| [face.p1, face.p2, face.p3].contains(point)));
+    }
+}
+
+#[test]
+fn collinear_vertices_add_no_degenerate_triangles() {
+    let square = polygon(&[
+        (0.0, 0.0, 0.0),
+        (1.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (2.0, 1.0, 0.0),
+        (2.0, 2.0, 0.0),
+        (1.0, 2.0, 0.0),
+        (0.0, 2.0, 0.0),
+        (0.0, 1.0, 0.0),
+    ]);
+    let faces = square.triangulate().unwrap();
+    assert_eq!(faces.len(), 6);
+    assert_upward(&faces);
+    assert_close(area(&faces), 4.0, 1e-12);
+
+    let line = polygon(&[(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (2.0, 0.0, 0.0)]);
+    assert!(line.triangulate().unwrap().is_empty());
+}
+
+#[test]
+fn self_crossing_outline_is_not_triangulated() {
+    let bowtie = polygon(&[
+        (0.0, 0.0, 0.0),
+        (1.0, 1.0, 0.0),
+        (1.0, 0.0, 0.0),
+        (0.0, 1.0, 0.0),
+        (-1.0, 1.0, 0.0),
+        (-1.0, 0.0, 0.0),
+    ]);
+    assert!(bowtie.triangulate().is_none());
+
+    let text = "v 0 0 0\nv 1 1 0\nv 1 0 0\nv 0 1 0\nv -1 1 0\nv -1 0 0\nf 1 2 3 4 5 6\n";
+    assert!(obj::read(text.as_bytes()).is_err());
+}
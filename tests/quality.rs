@@ -0,0 +1,82 @@
+mod common;
+
+use common::assert_close;
+use medial_axis_3d::{
+    point::Point,
+    quality::{quality_histogram, Histogram, Metric},
+    tetrahedron::Tetrahedron,
+};
+
+// every edge 2√2 long, centred on the origin
+fn regular() -> Tetrahedron {
+    Tetrahedron::new(
+        Point::new(1.0, 1.0, 1.0),
+        Point::new(1.0, -1.0, -1.0),
+        Point::new(-1.0, 1.0, -1.0),
+        Point::new(-1.0, -1.0, 1.0),
+    )
+}
+
+fn sliver() -> Tetrahedron {
+    Tetrahedron::new(
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(1.0, 0.0, 0.0),
+        Point::new(0.0, 1.0, 0.0),
+        Point::new(1.0, 1.0, 0.01),
+    )
+}
+
+#[test]
+fn regular_tetrahedron_has_equal_angles() {
+    let tetra = regular();
+    for &angle in tetra.dihedral_angles().iter() {
+        assert_close(angle, (1.0f64 / 3.0).acos(), 1e-12);
+    }
+    for &angle in tetra.solid_angles().iter() {
+        assert_close(
+            angle,
+            3.0 * (1.0f64 / 3.0).acos() - std::f64::consts::PI,
+            1e-12,
+        );
+    }
+    // the four solid angles together cover less than a sphere's 4π
+    assert!(tetra.solid_angles().iter().sum::<f64>() < 4.0 * std::f64::consts::PI);
+}
+
+#[test]
+fn regular_tetrahedron_has_the_best_ratios() {
+    let tetra = regular();
+    assert_close(tetra.volume(), 8.0 / 3.0, 1e-12);
+    assert_close(tetra.inradius(), 1.0 / 3f64.sqrt(), 1e-12);
+    assert_close(tetra.aspect_ratio(), 1.0, 1e-12);
+    assert_close(tetra.radius_edge_ratio(), 6f64.sqrt() / 4.0, 1e-12);
+
+    let sliver = sliver();
+    assert!(sliver.aspect_ratio() > 10.0);
+    assert!(Metric::MinDihedralAngle.evaluate(&sliver) < 5.0);
+    assert!(Metric::MaxDihedralAngle.evaluate(&sliver) > 175.0);
+}
+
+#[test]
+fn metrics_report_angles_in_degrees() {
+    let tetra = regular();
+    let dihedral = (1.0f64 / 3.0).acos().to_degrees();
+    assert_close(Metric::MinDihedralAngle.evaluate(&tetra), dihedral, 1e-12);
+    assert_close(Metric::MaxDihedralAngle.evaluate(&tetra), dihedral, 1e-12);
+    assert_close(Metric::MinSolidAngle.evaluate(&tetra), 0.551_285_598, 1e-8);
+}
+
+#[test]
+fn histogram_counts_every_finite_value() {
+    let histogram = Histogram::new(&[0.0, 1.0, 2.0, 3.0, 4.0, f64::NAN], 4);
+    assert_eq!(histogram.bins, vec![1, 1, 1, 2]);
+    assert_eq!(
+        (histogram.min, histogram.max, histogram.mean),
+        (0.0, 4.0, 2.0)
+    );
+    assert_eq!(histogram.bin_range(1), (1.0, 2.0));
+
+    let histogram = quality_histogram(&[regular(), sliver()], Metric::AspectRatio, 10);
+    assert_eq!(histogram.count(), 2);
+    assert_eq!((histogram.bins[0], histogram.bins[9]), (1, 1));
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use common::{assert_close, cuboid, surface_volume};
+use medial_axis_3d::{
+    face::Face,
+    point::Point,
+    reconstruction::{directed_hausdorff_distance, hausdorff_distance, union_of_balls},
+    repair,
+    spheres::Sphere,
+};
+use std::f64::consts::PI;
+
+fn translated(faces: &[Face], offset: Point) -> Vec<Face> {
+    faces
+        .iter()
+        .map(|face| Face::new(face.p1 + offset, face.p2 + offset, face.p3 + offset))
+        .collect()
+}
+
+#[test]
+fn single_ball_is_a_closed_sphere() {
+    let faces = union_of_balls(&[Sphere::new(Point::new(1.0, 2.0, 3.0), 2.0)], 0.1);
+    assert!(!faces.is_empty());
+    assert!(repair::boundary_loops(&faces).is_empty());
+    assert!(repair::non_manifold_edges(&faces).is_empty());
+    assert_close(surface_volume(&faces), 4.0 / 3.0 * PI * 8.0, 0.02);
+    for face in faces.iter() {
+        assert!((face.centroid().distance(&Point::new(1.0, 2.0, 3.0)) - 2.0).abs() < 0.1);
+    }
+}
+
+#[test]
+fn overlapping_balls_merge_into_one_surface() {
+    let balls = [
+        Sphere::new(Point::new(0.0, 0.0, 0.0), 1.0),
+        Sphere::new(Point::new(1.5, 0.0, 0.0), 1.0),
+    ];
+    let faces = union_of_balls(&balls, 0.05);
+    assert!(repair::boundary_loops(&faces).is_empty());
+    // two unit balls 1.5 apart overlap in a lens of volume pi (4 + 1.5) (2 - 1.5)² / 12
+    let expected = 2.0 * 4.0 / 3.0 * PI - PI * 5.5 * 0.25 / 12.0;
+    assert_close(surface_volume(&faces), expected, 0.02);
+}
+
+#[test]
+fn no_balls_or_spacing_give_no_surface() {
+    assert!(union_of_balls(&[], 0.1).is_empty());
+    assert!(union_of_balls(&[Sphere::new(Point::new(0.0, 0.0, 0.0), 1.0)], 0.0).is_empty());
+}
+
+#[test]
+fn hausdorff_distance_of_a_translated_box_is_the_offset() {
+    let faces = cuboid(Point::new(2.0, 3.0, 4.0)).faces;
+    assert_close(hausdorff_distance(&faces, &faces), 0.0, 1e-12);
+    let moved = translated(&faces, Point::new(0.0, 0.0, 0.5));
+    assert_close(hausdorff_distance(&faces, &moved), 0.5, 1e-9);
+}
+
+#[test]
+fn directed_hausdorff_distance_is_not_symmetric() {
+    let outer = cuboid(Point::new(4.0, 4.0, 4.0)).faces;
+    let inner = translated(
+        &cuboid(Point::new(2.0, 2.0, 2.0)).faces,
+        Point::new(1.0, 1.0, 1.0),
+    );
+    assert_close(directed_hausdorff_distance(&inner, &outer), 1.0, 1e-9);
+    assert!(directed_hausdorff_distance(&outer, &inner) > 1.0);
+    assert_eq!(directed_hausdorff_distance(&[], &outer), 0.0);
+    assert_eq!(directed_hausdorff_distance(&outer, &[]), f64::INFINITY);
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use common::{assert_close, cuboid, dino, surface_volume, volume};
+use medial_axis_3d::{
+    point::Point,
+    refine::{refine, RefineOptions},
+    tetrahedron::Tetrahedron,
+};
+
+fn min_dihedral_angle(tetra: &Tetrahedron) -> f64 {
+    tetra
+        .dihedral_angles()
+        .iter()
+        .copied()
+        .fold(f64::INFINITY, f64::min)
+        .to_degrees()
+}
+
+#[test]
+fn refined_box_meets_the_radius_edge_bound() {
+    let mesh = cuboid(Point::new(10.0, 3.0, 1.0));
+    let refinement = refine(&mesh.points, &mesh.faces, &RefineOptions::default()).unwrap();
+    assert!(!refinement.steiner_points.is_empty());
+    assert!(!refinement.budget_exhausted);
+    for tetra in refinement.tetrahedrons.iter() {
+        assert!(tetra.radius_edge_ratio() <= 2.0);
+    }
+    assert_close(volume(&refinement.tetrahedrons), 30.0, 1e-9);
+}
+
+#[test]
+fn refined_box_meets_the_dihedral_bound() {
+    let mesh = cuboid(Point::new(10.0, 3.0, 1.0));
+    let options = RefineOptions {
+        min_dihedral_angle: Some(10.0),
+        ..RefineOptions::default()
+    };
+    let refinement = refine(&mesh.points, &mesh.faces, &options).unwrap();
+    for tetra in refinement.tetrahedrons.iter() {
+        assert!(tetra.radius_edge_ratio() <= 2.0);
+        assert!(min_dihedral_angle(tetra) >= 10.0);
+    }
+    assert_close(volume(&refinement.tetrahedrons), 30.0, 1e-9);
+}
+
+#[test]
+fn refined_dino_meets_the_radius_edge_bound() {
+    let mesh = dino();
+    let options = RefineOptions {
+        max_radius_edge_ratio: 2.3,
+        ..RefineOptions::default()
+    };
+    let refinement = refine(&mesh.points, &mesh.faces, &options).unwrap();
+    for tetra in refinement.tetrahedrons.iter() {
+        assert!(tetra.radius_edge_ratio() <= 2.3);
+    }
+    assert_close(
+        volume(&refinement.tetrahedrons),
+        surface_volume(&mesh.faces).abs(),
+        1e-9,
+    );
+}
+
+#[test]
+fn refining_dino_below_its_sharpest_corner_terminates() {
+    // its sharpest corner forces a radius-edge ratio of 2.245, so the bound cannot be met there
+    let mesh = dino();
+    let options = RefineOptions {
+        max_radius_edge_ratio: 2.0,
+        ..RefineOptions::default()
+    };
+    let refinement = refine(&mesh.points, &mesh.faces, &options).unwrap();
+    assert!(refinement.steiner_points.len() < options.max_steiner_points);
+    assert!(!refinement.budget_exhausted);
+    assert_close(
+        volume(&refinement.tetrahedrons),
+        surface_volume(&mesh.faces).abs(),
+        1e-9,
+    );
+}
+
+#[test]
+fn running_out_of_steiner_points_is_reported() {
+    let mesh = cuboid(Point::new(10.0, 3.0, 1.0));
+    let options = RefineOptions {
+        max_steiner_points: 5,
+        ..RefineOptions::default()
+    };
+    let refinement = refine(&mesh.points, &mesh.faces, &options).unwrap();
+    assert!(refinement.budget_exhausted);
+    assert!(refinement.steiner_points.len() <= 5);
+    assert!(refinement
+        .tetrahedrons
+        .iter()
+        .any(|tetra| tetra.radius_edge_ratio() > 2.0));
+    assert_close(volume(&refinement.tetrahedrons), 30.0, 1e-9);
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use common::assert_close;
+use medial_axis_3d::{delaunay, point::Point, regular::regular_triangulation, voronoi::Bounds};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn weighted(count: usize, max_weight: f64, seed: u64) -> Vec<(Point, f64)> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..count)
+        .map(|_| {
+            let point = Point::new(
+                rng.gen_range(0.0..10.0),
+                rng.gen_range(0.0..10.0),
+                rng.gen_range(0.0..10.0),
+            );
+            (point, rng.gen_range(0.0..=max_weight))
+        })
+        .collect()
+}
+
+#[test]
+fn zero_weights_give_the_delaunay_tetrahedralization() {
+    let points = weighted(50, 0.0, 1);
+    let regular = regular_triangulation(&points);
+    let positions = points.iter().map(|&(point, _)| point).collect::<Vec<_>>();
+    assert_eq!(regular.tetrahedrons.len(), delaunay(&positions).len());
+    assert!(regular.hidden.is_empty());
+    for tetra in regular.tetrahedrons.iter() {
+        assert!(tetra.orthocenter.distance(&tetra.tetra.circumcenter) < 1e-6);
+    }
+}
+
+#[test]
+fn no_visible_point_is_closer_in_power_than_the_orthosphere() {
+    let points = weighted(60, 2.0, 2);
+    let regular = regular_triangulation(&points);
+    for tetra in regular.tetrahedrons.iter() {
+        for (i, &(point, weight)) in points.iter().enumerate() {
+            if !regular.hidden.contains(&i) {
+                assert!(tetra.power(&point, weight) >= -1e-6);
+            }
+        }
+    }
+}
+
+#[test]
+fn heavy_point_hides_a_light_neighbour() {
+    let mut points = weighted(30, 0.0, 3);
+    points.push((Point::new(5.0, 5.0, 5.0), 9.0));
+    points.push((Point::new(5.1, 5.0, 5.0), 0.0));
+    let regular = regular_triangulation(&points);
+    assert!(regular.hidden.contains(&(points.len() - 1)));
+    assert!(!regular.hidden.contains(&(points.len() - 2)));
+}
+
+#[test]
+fn power_cells_fill_the_bounds() {
+    let points = weighted(40, 1.0, 4);
+    let regular = regular_triangulation(&points);
+    let bounds = Bounds::Box {
+        min: Point::new(0.0, 0.0, 0.0),
+        max: Point::new(10.0, 10.0, 10.0),
+    };
+    let cells = regular.power_cells(&bounds).unwrap();
+    let volume = cells.iter().map(|cell| cell.volume).sum::<f64>();
+    assert_close(volume, 1000.0, 1e-6);
+}
@@ -0,0 +1,129 @@
+mod common;
+
+use common::{assert_close, cuboid, surface_volume};
+use medial_axis_3d::{
+    face::Face,
+    point::Point,
+    polygon::Polygon,
+    repair::{
+        boundary_loops, face_vertices, fill_holes, fix_orientation, remove_degenerate_faces,
+        remove_duplicate_faces, repair, weld_vertices, RepairOptions,
+    },
+};
+use std::collections::HashSet;
+
+fn unit_cube() -> Vec<Face> {
+    cuboid(Point::new(1.0, 1.0, 1.0)).faces
+}
+
+// every edge is used once in each direction by a closed and consistently oriented surface
+fn is_closed_and_oriented(faces: &[Face]) -> bool {
+    let key = |p: &Point| (p.x.to_bits(), p.y.to_bits(), p.z.to_bits());
+    let mut directed = HashSet::new();
+    for face in faces {
+        for (a, b) in [(face.p1, face.p2), (face.p2, face.p3), (face.p3, face.p1)].iter() {
+            if !directed.insert((key(a), key(b))) {
+                return false;
+            }
+        }
+    }
+    directed.iter().all(|&(a, b)| directed.contains(&(b, a)))
+}
+
+#[test]
+fn nearby_vertices_are_welded() {
+    let faces = unit_cube()
+        .iter()
+        .enumerate()
+        .map(|(i, face)| {
+            let jitter = Point::new(1e-8, -1e-8, 1e-8) * (i % 3) as f64;
+            Face::new(face.p1 + jitter, face.p2 - jitter, face.p3 + jitter)
+        })
+        .collect::<Vec<_>>();
+    assert!(face_vertices(&faces).len() > 8);
+
+    let welded = weld_vertices(&faces, 1e-6);
+    assert_eq!(welded.len(), faces.len());
+    assert_eq!(face_vertices(&welded).len(), 8);
+    assert!(is_closed_and_oriented(&welded));
+}
+
+#[test]
+fn degenerate_and_duplicate_faces_are_removed() {
+    let mut faces = unit_cube();
+    let (a, b) = (Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0));
+    faces.push(Face::new(a, b, (a + b) / 2.0));
+    faces.push(Face::new(a, a, b));
+    let duplicate = faces[3];
+    faces.push(Face::new(duplicate.p2, duplicate.p3, duplicate.p1));
+    faces.push(Face::new(duplicate.p1, duplicate.p3, duplicate.p2));
+
+    let cleaned = remove_duplicate_faces(&remove_degenerate_faces(&faces));
+    assert_eq!(cleaned.len(), 12);
+    assert!(is_closed_and_oriented(&cleaned));
+}
+
+#[test]
+fn orientation_is_made_consistent_and_outward() {
+    let flip = |face: &Face| Face::new(face.p1, face.p3, face.p2);
+    let cube = unit_cube();
+    let some_flipped = cube
+        .iter()
+        .enumerate()
+        .map(|(i, face)| if i % 3 == 0 { flip(face) } else { *face })
+        .collect::<Vec<_>>();
+    let inside_out = cube.iter().map(flip).collect::<Vec<_>>();
+
+    for faces in [some_flipped, inside_out].iter() {
+        assert!(!is_closed_and_oriented(faces) || surface_volume(faces) < 0.0);
+        let fixed = fix_orientation(faces);
+        assert!(is_closed_and_oriented(&fixed));
+        assert_close(surface_volume(&fixed), 1.0, 1e-12);
+    }
+}
+
+#[test]
+fn cube_with_a_missing_face_is_closed() {
+    let mut faces = unit_cube();
+    faces.drain(..2);
+    assert_eq!(boundary_loops(&faces).len(), 1);
+
+    let filled = fill_holes(&faces);
+    assert_eq!(filled.len(), 12);
+    assert!(boundary_loops(&filled).is_empty());
+    assert!(is_closed_and_oriented(&filled));
+    assert_close(surface_volume(&filled), 1.0, 1e-12);
+}
+
+#[test]
+fn non_convex_hole_is_filled_without_overlaps() {
+    // an L-shaped prism without its top, whose outline a fan from a far corner would overshoot
+    let outline = [
+        (0.0, 0.0),
+        (2.0, 0.0),
+        (2.0, 1.0),
+        (1.0, 1.0),
+        (1.0, 2.0),
+        (0.0, 2.0),
+    ];
+    let at = |(x, y): (f64, f64), z: f64| Point::new(x, y, z);
+    let bottom = outline.iter().rev().map(|&p| at(p, 0.0)).collect();
+    let mut faces = Polygon::new(bottom).triangulate().unwrap();
+    for i in 0..outline.len() {
+        let (a, b) = (outline[i], outline[(i + 1) % outline.len()]);
+        faces.push(Face::new(at(a, 0.0), at(b, 0.0), at(b, 1.0)));
+        faces.push(Face::new(at(a, 0.0), at(b, 1.0), at(a, 1.0)));
+    }
+
+    // the hole is walked from a different corner each time
+    for start in 0..outline.len() {
+        let mut rotated = faces.clone();
+        rotated.rotate_left(start);
+        let filled = repair(&rotated, &RepairOptions::default());
+        assert!(is_closed_and_oriented(&filled));
+        let lid = &filled[faces.len()..];
+        assert_eq!(lid.len(), 4);
+        assert_close(lid.iter().map(Face::area).sum(), 3.0, 1e-12);
+        assert_close(surface_volume(&filled), 3.0, 1e-12);
+    }
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use common::{assert_close, cuboid, dino, surface_volume, volume};
+use medial_axis_3d::{
+    constrained::constrained_delaunay,
+    face::Face,
+    point::Point,
+    sampling::{adaptive_samples, sample_surface, uniform_samples, Sampling},
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+fn sampled_interior_volume(points: &[Point], faces: &[Face]) -> f64 {
+    let constrained = constrained_delaunay(points, faces, 10 * points.len()).unwrap();
+    assert!(constrained.missing_subfaces().is_empty());
+    volume(&constrained.interior())
+}
+
+#[test]
+fn sampled_subfaces_cover_the_input_surface() {
+    let mesh = dino();
+    let mut rng = StdRng::seed_from_u64(1);
+    let (points, faces) = sample_surface(
+        &mesh.points,
+        &mesh.faces,
+        &Sampling::PoissonDisk { radius: 1.0 },
+        &mut rng,
+    );
+    assert!(points.len() > mesh.points.len());
+    assert!(faces.len() > mesh.faces.len());
+    assert_close(surface_volume(&faces), surface_volume(&mesh.faces), 1e-9);
+}
+
+#[test]
+fn poisson_samples_keep_their_distance_from_the_vertices() {
+    let mesh = cuboid(Point::new(10.0, 10.0, 10.0));
+    let mut rng = StdRng::seed_from_u64(2);
+    let (points, _) = sample_surface(
+        &mesh.points,
+        &mesh.faces,
+        &Sampling::PoissonDisk { radius: 2.0 },
+        &mut rng,
+    );
+    assert!(points.len() > mesh.points.len());
+    for sample in points[mesh.points.len()..].iter() {
+        for point in points.iter().filter(|point| *point != sample) {
+            assert!(sample.distance(point) >= 2.0);
+        }
+    }
+}
+
+#[test]
+fn poisson_sampled_dino_interior_matches_surface_volume() {
+    let mesh = dino();
+    let mut rng = StdRng::seed_from_u64(3);
+    let (points, faces) = sample_surface(
+        &mesh.points,
+        &mesh.faces,
+        &Sampling::PoissonDisk { radius: 1.0 },
+        &mut rng,
+    );
+    assert_close(
+        sampled_interior_volume(&points, &faces),
+        surface_volume(&mesh.faces).abs(),
+        1e-9,
+    );
+}
+
+#[test]
+fn uniformly_sampled_box_interior_fills_the_box() {
+    let mesh = cuboid(Point::new(10.0, 10.0, 10.0));
+    let mut rng = StdRng::seed_from_u64(4);
+    let (points, faces) = sample_surface(
+        &mesh.points,
+        &mesh.faces,
+        &Sampling::Uniform { count: 500 },
+        &mut rng,
+    );
+    assert!(points.len() > mesh.points.len());
+    assert_close(sampled_interior_volume(&points, &faces), 1000.0, 1e-9);
+}
+
+#[test]
+fn uniform_sampling_draws_the_requested_count() {
+    let mesh = cuboid(Point::new(10.0, 10.0, 10.0));
+    let mut rng = StdRng::seed_from_u64(5);
+    for &count in [20, 50, 500].iter() {
+        assert_eq!(uniform_samples(&mesh.faces, count, &mut rng).len(), count);
+    }
+
+    let (points, faces) = sample_surface(
+        &mesh.points,
+        &mesh.faces,
+        &Sampling::Uniform { count: 200 },
+        &mut rng,
+    );
+    assert_eq!(points.len(), mesh.points.len() + 200);
+    assert!(faces.iter().all(|face| face.area() > 1e-9));
+}
+
+#[test]
+fn adaptive_sampling_with_an_invalid_epsilon_draws_nothing() {
+    let mesh = cuboid(Point::new(10.0, 10.0, 10.0));
+    let mut rng = StdRng::seed_from_u64(6);
+    for &epsilon in [f64::NAN, f64::INFINITY, 0.0, -1.0].iter() {
+        assert!(adaptive_samples(&mesh.points, &mesh.faces, epsilon, &mut rng).is_empty());
+    }
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use common::cuboid;
+use medial_axis_3d::{
+    delaunay, edge::Edge, face::Face, medial_axis, point::Point, point_inside_shape,
+    scalar::Interval,
+};
+use std::collections::HashSet;
+
+fn single(point: &Point) -> Point<f32> {
+    Point::new(point.x as f32, point.y as f32, point.z as f32)
+}
+
+fn interval(point: &Point) -> Point<Interval> {
+    Point::new(point.x.into(), point.y.into(), point.z.into())
+}
+
+fn spiral() -> Vec<Point> {
+    (0..20)
+        .map(|i| {
+            let i = i as f64;
+            Point::new((i * 1.3).sin() * 4.0, (i * 0.7).cos() * 3.0, i / 5.0)
+        })
+        .collect()
+}
+
+// every edge of `a` has an edge of `b` with both ends within the tolerance, either way round
+fn assert_edges_match(a: &[Edge], b: &[Edge], tolerance: f64) {
+    assert_eq!(a.len(), b.len());
+    for edge in a.iter() {
+        assert!(
+            b.iter().any(|other| {
+                (edge.p1.distance(&other.p1) < tolerance && edge.p2.distance(&other.p2) < tolerance)
+                    || (edge.p1.distance(&other.p2) < tolerance
+                        && edge.p2.distance(&other.p1) < tolerance)
+            }),
+            "{:?} has no match",
+            edge
+        );
+    }
+}
+
+#[test]
+fn single_precision_medial_axis_matches_double() {
+    let points = spiral();
+    let double = medial_axis(&delaunay(&points));
+    let single = medial_axis(&delaunay(&points.iter().map(single).collect::<Vec<_>>()))
+        .iter()
+        .map(|edge| {
+            let double = |p: Point<f32>| Point::new(p.x as f64, p.y as f64, p.z as f64);
+            Edge::new(double(edge.p1), double(edge.p2))
+        })
+        .collect::<Vec<_>>();
+    assert_edges_match(&single, &double, 1e-3);
+}
+
+#[test]
+fn interval_equality_is_structural() {
+    let wide = Interval::new(1.0, 2.0);
+    assert_eq!(wide, wide);
+    assert_ne!(wide, Interval::new(1.0, 2.5));
+    assert_eq!(wide.partial_cmp(&wide), None);
+
+    let point = Point::new(wide, wide, wide);
+    assert_eq!(point, point);
+    assert!(std::iter::once(point)
+        .collect::<HashSet<_>>()
+        .contains(&point));
+}
+
+#[test]
+fn interval_delaunay_encloses_double() {
+    let points = spiral();
+    let double = delaunay(&points);
+    let intervals = delaunay(&points.iter().map(interval).collect::<Vec<_>>());
+    assert_eq!(intervals.len(), double.len());
+    for tetra in intervals.iter() {
+        let corners = [tetra.p1, tetra.p2, tetra.p3, tetra.p4];
+        let exact = |p: &Point<Interval>| Point::new(p.x.lo, p.y.lo, p.z.lo);
+        let matching = double
+            .iter()
+            .find(|other| corners.iter().all(|corner| other.has_point(&exact(corner))))
+            .unwrap();
+        let encloses =
+            |range: Interval, value: f64| range.lo - 1e-9 <= value && value <= range.hi + 1e-9;
+        let (center, expected) = (tetra.circumcenter, matching.circumcenter);
+        assert!(encloses(center.x, expected.x));
+        assert!(encloses(center.y, expected.y));
+        assert!(encloses(center.z, expected.z));
+    }
+}
+
+#[test]
+fn single_precision_point_inside_shape() {
+    let faces = cuboid(Point::new(2.0, 2.0, 2.0))
+        .faces
+        .iter()
+        .map(|face| Face::new(single(&face.p1), single(&face.p2), single(&face.p3)))
+        .collect::<Vec<_>>();
+    assert!(point_inside_shape(&Point::new(1.1f32, 0.9, 1.2), &faces));
+    assert!(!point_inside_shape(&Point::new(3.1f32, 0.9, 1.2), &faces));
+    assert!(!point_inside_shape(&Point::new(1.1f32, 0.9, -1.0), &faces));
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "serde")]
+
+use medial_axis_3d::{
+    constrained::constrained_delaunay, delaunay, face::Face, io::Mesh, point::Point,
+    sampling::Sampling, skeleton::Skeleton, spheres::medial_balls, tetrahedron::Tetrahedron,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+fn cube() -> Mesh {
+    let p = |x, y, z| Point::new(x, y, z);
+    let points = vec![
+        p(0.0, 0.0, 0.0),
+        p(1.0, 0.0, 0.0),
+        p(1.0, 1.0, 0.0),
+        p(0.0, 1.0, 0.0),
+        p(0.0, 0.0, 1.0),
+        p(1.0, 0.0, 1.0),
+        p(1.0, 1.0, 1.0),
+        p(0.0, 1.0, 1.0),
+    ];
+    let faces = [
+        [0, 2, 1],
+        [0, 3, 2],
+        [4, 5, 6],
+        [4, 6, 7],
+        [0, 1, 5],
+        [0, 5, 4],
+        [1, 2, 6],
+        [1, 6, 5],
+        [2, 3, 7],
+        [2, 7, 6],
+        [3, 0, 4],
+        [3, 4, 7],
+    ]
+    .iter()
+    .map(|&[a, b, c]| Face::new(points[a], points[b], points[c]))
+    .collect();
+    Mesh { points, faces }
+}
+
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> (T, T) {
+    let json = serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap();
+    let binary = bincode::deserialize(&bincode::serialize(value).unwrap()).unwrap();
+    (json, binary)
+}
+
+fn same_tetrahedron(a: &Tetrahedron, b: &Tetrahedron) -> bool {
+    a.p1 == b.p1
+        && a.p2 == b.p2
+        && a.p3 == b.p3
+        && a.p4 == b.p4
+        && a.circumcenter == b.circumcenter
+        && a.circumradius == b.circumradius
+}
+
+#[test]
+fn mesh_round_trip() {
+    let mesh = cube();
+    for decoded in <[Mesh; 2]>::from(round_trip(&mesh)).iter() {
+        assert_eq!(decoded.points, mesh.points);
+        assert_eq!(decoded.faces, mesh.faces);
+    }
+}
+
+#[test]
+fn triangulation_round_trip() {
+    let mesh = cube();
+    let tetrahedrons = delaunay(&mesh.points);
+    for decoded in <[Vec<Tetrahedron>; 2]>::from(round_trip(&tetrahedrons)).iter() {
+        assert_eq!(decoded.len(), tetrahedrons.len());
+        assert!(decoded
+            .iter()
+            .zip(tetrahedrons.iter())
+            .all(|(a, b)| same_tetrahedron(a, b)));
+    }
+
+    let constrained = constrained_delaunay(&mesh.points, &mesh.faces, 100).unwrap();
+    let (json, binary) = round_trip(&constrained);
+    for decoded in [json, binary].iter() {
+        assert_eq!(decoded.subfaces, constrained.subfaces);
+        assert_eq!(decoded.steiner_points, constrained.steiner_points);
+        assert!(decoded
+            .tetrahedrons
+            .iter()
+            .zip(constrained.tetrahedrons.iter())
+            .all(|(a, b)| same_tetrahedron(a, b)));
+    }
+}
+
+#[test]
+fn skeleton_round_trip() {
+    let mesh = cube();
+    let tetrahedrons = constrained_delaunay(&mesh.points, &mesh.faces, 100)
+        .unwrap()
+        .interior();
+    let skeleton = Skeleton::new(&tetrahedrons);
+    for decoded in <[Skeleton; 2]>::from(round_trip(&skeleton)).iter() {
+        assert_eq!(decoded.nodes, skeleton.nodes);
+        assert_eq!(decoded.radii, skeleton.radii);
+        assert_eq!(decoded.edges, skeleton.edges);
+    }
+
+    let armature = skeleton.armature();
+    let (json, binary) = round_trip(&armature);
+    for decoded in [json, binary].iter() {
+        assert_eq!(decoded.bones(), armature.bones());
+    }
+
+    let balls = medial_balls(&tetrahedrons);
+    let (json, binary) = round_trip(&balls);
+    assert_eq!(json, balls);
+    assert_eq!(binary, balls);
+}
+
+#[test]
+fn options_round_trip() {
+    let sampling = Sampling::PoissonDisk { radius: 0.25 };
+    let (json, binary) = round_trip(&sampling);
+    for decoded in [json, binary].iter() {
+        assert!(matches!(decoded, Sampling::PoissonDisk { radius } if *radius == 0.25));
+    }
+}
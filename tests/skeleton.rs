@@ -0,0 +1,179 @@
+mod common;
+
+use common::dino;
+use medial_axis_3d::{
+    constrained::constrained_delaunay,
+    io::skeleton::{save, write_dot, write_graphml, write_json},
+    point::Point,
+    skeleton::Skeleton,
+};
+use std::{env, fs, io};
+
+fn dino_skeleton() -> Skeleton {
+    let mesh = dino();
+    let constrained =
+        constrained_delaunay(&mesh.points, &mesh.faces, 10 * mesh.points.len()).unwrap();
+    Skeleton::new(&constrained.interior())
+}
+
+fn fork() -> Skeleton {
+    Skeleton {
+        nodes: vec![
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.5, 0.0, 0.0),
+            Point::new(0.0, -2.0, 0.25),
+        ],
+        radii: vec![1.0, 0.5, 0.125],
+        edges: vec![(0, 1), (0, 2)],
+    }
+}
+
+fn written(write: fn(&mut Vec<u8>, &Skeleton) -> io::Result<()>, skeleton: &Skeleton) -> String {
+    let mut bytes = Vec::new();
+    write(&mut bytes, skeleton).unwrap();
+    String::from_utf8(bytes).unwrap()
+}
+
+#[test]
+fn curve_skeleton_is_a_tree() {
+    let skeleton = dino_skeleton().curve_skeleton();
+    assert!(!skeleton.nodes.is_empty());
+    assert_eq!(skeleton.edges.len(), skeleton.nodes.len() - 1);
+}
+
+#[test]
+fn dino_armature_has_a_joint_per_limb() {
+    let skeleton = dino_skeleton();
+    let armature = skeleton.armature();
+    let leaves = skeleton
+        .curve_skeleton()
+        .neighbours()
+        .iter()
+        .filter(|neighbours| neighbours.len() == 1)
+        .count();
+    // a head, a tail and four legs at least, but no joint for every wrinkle of the surface
+    assert!(leaves >= 6);
+    assert!(armature.joints.len() < 30);
+    assert_eq!(armature.bones().len(), armature.joints.len() - 1);
+    for joint in armature.joints.iter() {
+        assert!(joint.node < skeleton.nodes.len());
+    }
+}
+
+#[test]
+fn pruned_skeleton_keeps_its_armature() {
+    // pruning renumbers the nodes, which must not stop branches from being removed afterwards
+    let skeleton = dino_skeleton().pruned(0.0);
+    assert!(skeleton
+        .edges
+        .iter()
+        .all(|&(a, b)| a < b && b < skeleton.nodes.len()));
+    let armature = skeleton.pruned(1.0).armature();
+    assert!(!armature.joints.is_empty());
+    assert!(armature.joints.len() < 30);
+}
+
+#[test]
+fn json_output_matches_its_snapshot() {
+    let json = written(|writer, skeleton| write_json(writer, skeleton), &fork());
+    assert_eq!(
+        json,
+        r#"{
+  "nodes": [
+    {"id": 0, "position": [0, 0, 0], "radius": 1},
+    {"id": 1, "position": [1.5, 0, 0], "radius": 0.5},
+    {"id": 2, "position": [0, -2, 0.25], "radius": 0.125}
+  ],
+  "edges": [
+    {"source": 0, "target": 1},
+    {"source": 0, "target": 2}
+  ]
+}
+"#
+    );
+    serde_json::from_str::<serde_json::Value>(&json).unwrap();
+}
+
+#[test]
+fn json_output_writes_non_finite_radii_as_null() {
+    let mut skeleton = fork();
+    skeleton.radii[0] = f64::INFINITY;
+    let json = written(|writer, skeleton| write_json(writer, skeleton), &skeleton);
+    let value = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+    assert!(value["nodes"][0]["radius"].is_null());
+    assert_eq!(value["nodes"][1]["radius"], 0.5);
+}
+
+#[test]
+fn graphml_output_matches_its_snapshot() {
+    let graphml = written(|writer, skeleton| write_graphml(writer, skeleton), &fork());
+    assert_eq!(
+        graphml,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="x" for="node" attr.name="x" attr.type="double"/>
+  <key id="y" for="node" attr.name="y" attr.type="double"/>
+  <key id="z" for="node" attr.name="z" attr.type="double"/>
+  <key id="radius" for="node" attr.name="radius" attr.type="double"/>
+  <graph id="skeleton" edgedefault="undirected">
+    <node id="n0">
+      <data key="x">0</data>
+      <data key="y">0</data>
+      <data key="z">0</data>
+      <data key="radius">1</data>
+    </node>
+    <node id="n1">
+      <data key="x">1.5</data>
+      <data key="y">0</data>
+      <data key="z">0</data>
+      <data key="radius">0.5</data>
+    </node>
+    <node id="n2">
+      <data key="x">0</data>
+      <data key="y">-2</data>
+      <data key="z">0.25</data>
+      <data key="radius">0.125</data>
+    </node>
+    <edge id="e0" source="n0" target="n1"/>
+    <edge id="e1" source="n0" target="n2"/>
+  </graph>
+</graphml>
+"#
+    );
+}
+
+#[test]
+fn dot_output_matches_its_snapshot() {
+    let dot = written(|writer, skeleton| write_dot(writer, skeleton), &fork());
+    assert_eq!(
+        dot,
+        r#"graph skeleton {
+  0 [pos="0,0,0", radius=1];
+  1 [pos="1.5,0,0", radius=0.5];
+  2 [pos="0,-2,0.25", radius=0.125];
+  0 -- 1;
+  0 -- 2;
+}
+"#
+    );
+}
+
+#[test]
+fn save_picks_the_format_from_the_extension() {
+    let skeleton = fork();
+    for (extension, start) in [
+        ("json", "{"),
+        ("graphml", "<?xml"),
+        ("dot", "graph skeleton"),
+        ("gv", "graph skeleton"),
+        ("obj", "v 0 0 0"),
+    ]
+    .iter()
+    {
+        let path = env::temp_dir().join(format!("medial_axis_3d_skeleton.{}", extension));
+        save(&path, &skeleton).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(text.starts_with(start), "{}: {}", extension, text);
+    }
+}
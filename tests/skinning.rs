@@ -0,0 +1,113 @@
+mod common;
+
+use common::{assert_close, cuboid};
+use medial_axis_3d::{
+    face::Face,
+    io::skin,
+    point::Point,
+    skeleton::{Armature, Joint},
+    skinning::{skinning_weights, Skin, MAX_INFLUENCES},
+};
+
+// a root with a bone along x and a leaf bone up from its end
+fn armature() -> Armature {
+    let joint = |x, y, parent| Joint {
+        position: Point::new(x, y, 0.0),
+        parent,
+        node: 0,
+    };
+    Armature {
+        joints: vec![
+            joint(0.0, 0.0, None),
+            joint(2.0, 0.0, Some(0)),
+            joint(2.0, 2.0, Some(1)),
+        ],
+    }
+}
+
+fn weight(skin: &Skin, vertex: usize, joint: usize) -> f64 {
+    skin.joints[vertex]
+        .iter()
+        .zip(skin.weights[vertex].iter())
+        .filter(|(&other, _)| other == joint)
+        .map(|(_, &weight)| weight)
+        .sum()
+}
+
+#[test]
+fn weights_sum_to_one() {
+    // a box around the armature
+    let offset = Point::new(0.5, 0.5, 0.5);
+    let mesh = cuboid(Point::new(3.0, 3.0, 1.0));
+    let points = mesh
+        .points
+        .iter()
+        .map(|&point| point - offset)
+        .collect::<Vec<_>>();
+    let faces = mesh
+        .faces
+        .iter()
+        .map(|face| Face::new(face.p1 - offset, face.p2 - offset, face.p3 - offset))
+        .collect::<Vec<_>>();
+    let skin = skinning_weights(&points, &faces, &armature());
+    assert_eq!(skin.weights.len(), points.len());
+    for (joints, weights) in skin.joints.iter().zip(skin.weights.iter()) {
+        assert_close(weights.iter().sum(), 1.0, 1e-12);
+        for (&joint, &weight) in joints.iter().zip(weights.iter()) {
+            assert!(weight >= 0.0);
+            assert!(joint < 3);
+        }
+    }
+}
+
+#[test]
+fn vertex_next_to_a_bone_is_dominated_by_it() {
+    let points = [Point::new(1.0, 0.1, 0.0), Point::new(2.1, 1.0, 0.0)];
+    let skin = skinning_weights(&points, &[], &armature());
+    // halfway along the first bone, shared by its joints
+    assert!(weight(&skin, 0, 0) + weight(&skin, 0, 1) > 0.9);
+    assert!(weight(&skin, 0, 0) > 0.4 && weight(&skin, 0, 1) > 0.4);
+    // halfway along the leaf bone
+    assert!(weight(&skin, 1, 1) + weight(&skin, 1, 2) > 0.9);
+}
+
+#[test]
+fn leaf_joints_are_weighted() {
+    let skin = skinning_weights(&[Point::new(2.0, 2.1, 0.0)], &[], &armature());
+    assert!(weight(&skin, 0, 2) > 0.5);
+}
+
+#[test]
+fn hidden_vertex_falls_back_to_the_nearest_bone() {
+    // a wall between the vertex and every bone
+    let wall = [
+        Face::new(
+            Point::new(-10.0, -10.0, 0.5),
+            Point::new(10.0, -10.0, 0.5),
+            Point::new(10.0, 10.0, 0.5),
+        ),
+        Face::new(
+            Point::new(-10.0, -10.0, 0.5),
+            Point::new(10.0, 10.0, 0.5),
+            Point::new(-10.0, 10.0, 0.5),
+        ),
+    ];
+    let skin = skinning_weights(&[Point::new(0.5, 0.0, 1.0)], &wall, &armature());
+    assert_close(weight(&skin, 0, 0) + weight(&skin, 0, 1), 1.0, 1e-12);
+    assert_eq!(weight(&skin, 0, 2), 0.0);
+}
+
+#[test]
+fn weights_are_written_as_csv() {
+    let points = [Point::new(1.0, 0.1, 0.0)];
+    let skin = skinning_weights(&points, &[], &armature());
+    let mut bytes = Vec::new();
+    skin::write_csv(&mut bytes, &points, &skin).unwrap();
+    let text = String::from_utf8(bytes).unwrap();
+    let lines = text.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1].split(',').count(), 3 + 2 * MAX_INFLUENCES);
+
+    let error = skin::write_csv(&mut Vec::new(), &points[..0], &skin).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+}
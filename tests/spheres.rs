@@ -0,0 +1,102 @@
+mod common;
+
+use common::dino;
+use medial_axis_3d::{
+    constrained::constrained_delaunay,
+    io::spheres,
+    point::Point,
+    spheres::{medial_balls, select_spheres, Sphere},
+};
+use std::io;
+
+fn dino_balls() -> (Vec<Sphere>, Vec<Point>) {
+    let mesh = dino();
+    let constrained =
+        constrained_delaunay(&mesh.points, &mesh.faces, 10 * mesh.points.len()).unwrap();
+    (medial_balls(&constrained.interior()), mesh.points)
+}
+
+#[test]
+fn selection_stops_at_the_target_count() {
+    let (balls, surface) = dino_balls();
+    for &count in [1, 5, 20].iter() {
+        assert_eq!(select_spheres(&balls, &surface, count, 0.1).len(), count);
+    }
+    assert!(select_spheres(&balls, &surface, 0, 0.1).is_empty());
+}
+
+#[test]
+fn selection_covers_the_surface_within_the_error() {
+    let (balls, surface) = dino_balls();
+    for &max_error in [0.01, 1.0].iter() {
+        let selected = select_spheres(&balls, &surface, balls.len(), max_error);
+        // it stops as soon as every point is covered, long before using every ball
+        assert!(selected.len() < balls.len() / 2);
+        for point in surface.iter() {
+            assert!(selected
+                .iter()
+                .any(|sphere| sphere.covers(point, max_error)));
+        }
+    }
+
+    let coarse = select_spheres(&balls, &surface, balls.len(), 1.0).len();
+    let fine = select_spheres(&balls, &surface, balls.len(), 0.01).len();
+    assert!(coarse <= fine);
+}
+
+#[test]
+fn first_selected_sphere_covers_the_most() {
+    let surface = (0..10)
+        .map(|i| Point::new(i as f64, 0.0, 0.0))
+        .collect::<Vec<_>>();
+    let balls = [
+        Sphere::new(Point::new(1.0, 0.0, 0.0), 1.0),
+        Sphere::new(Point::new(6.0, 0.0, 0.0), 3.0),
+        Sphere::new(Point::new(7.0, 0.0, 0.0), 3.0),
+    ];
+    let selected = select_spheres(&balls, &surface, 3, 0.0);
+    assert_eq!(selected[0], balls[1]);
+    // the third ball adds nothing once the second covers 3 to 9
+    assert_eq!(selected, vec![balls[1], balls[0]]);
+}
+
+fn sample() -> Vec<Sphere> {
+    vec![
+        Sphere::new(Point::new(0.5, -1.25, 3.0), 0.75),
+        Sphere::new(Point::new(100.0, 0.0, -7.5), 12.5),
+    ]
+}
+
+#[test]
+fn binary_sphere_set_round_trips() {
+    let mut bytes = Vec::new();
+    spheres::write_binary(&mut bytes, &sample()).unwrap();
+    assert_eq!(&bytes[..4], b"MASP");
+    assert_eq!(bytes.len(), 12 + 2 * 16);
+    assert_eq!(spheres::parse_binary(&bytes).unwrap(), sample());
+
+    let error = spheres::parse_binary(&bytes[..bytes.len() - 1]).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    bytes[0] = b'X';
+    assert!(spheres::parse_binary(&bytes).is_err());
+}
+
+#[test]
+fn json_sphere_set_lists_centers_and_radii() {
+    let mut bytes = Vec::new();
+    spheres::write_json(&mut bytes, &sample()).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    let read = json["spheres"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|sphere| {
+            let center = |i: usize| sphere["center"][i].as_f64().unwrap();
+            Sphere::new(
+                Point::new(center(0), center(1), center(2)),
+                sphere["radius"].as_f64().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(read, sample());
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use common::{assert_close, cuboid, surface_volume};
+use medial_axis_3d::{io::stl, point::Point, repair};
+
+fn ascii(faces: &[[Point; 3]]) -> String {
+    let mut text = "solid test\n".to_string();
+    for face in faces.iter() {
+        text += "facet normal 0 0 0\nouter loop\n";
+        for p in face.iter() {
+            text += &format!("vertex {} {} {}\n", p.x, p.y, p.z);
+        }
+        text += "endloop\nendfacet\n";
+    }
+    text + "endsolid test\n"
+}
+
+fn binary(faces: &[[Point; 3]]) -> Vec<u8> {
+    let mut bytes = vec![0; 80];
+    bytes.extend((faces.len() as u32).to_le_bytes());
+    for face in faces.iter() {
+        bytes.extend([0.0f32; 3].iter().flat_map(|x| x.to_le_bytes()));
+        for p in face.iter() {
+            for &x in [p.x, p.y, p.z].iter() {
+                bytes.extend((x as f32).to_le_bytes());
+            }
+        }
+        bytes.extend([0, 0]);
+    }
+    bytes
+}
+
+// the faces of a 10 unit cube with every vertex moved a little, differently in every facet
+fn jittered_cube() -> Vec<[Point; 3]> {
+    let mesh = cuboid(Point::new(10.0, 10.0, 10.0));
+    mesh.faces
+        .iter()
+        .enumerate()
+        .map(|(i, face)| {
+            let jitter = Point::new(2e-6, -2e-6, 2e-6) * (i % 3) as f64;
+            [face.p1 + jitter, face.p2 - jitter, face.p3 + jitter]
+        })
+        .collect()
+}
+
+#[test]
+fn ascii_facets_share_welded_vertices() {
+    let mesh = stl::parse(ascii(&jittered_cube()).as_bytes()).unwrap();
+    assert_eq!(mesh.points.len(), 8);
+    assert_eq!(mesh.faces.len(), 12);
+    assert!(repair::boundary_loops(&mesh.faces).is_empty());
+    assert_close(surface_volume(&mesh.faces).abs(), 1000.0, 1e-6);
+}
+
+#[test]
+fn binary_facets_share_welded_vertices() {
+    let mesh = stl::parse(&binary(&jittered_cube())).unwrap();
+    assert_eq!(mesh.points.len(), 8);
+    assert_eq!(mesh.faces.len(), 12);
+    assert!(repair::boundary_loops(&mesh.faces).is_empty());
+}
+
+#[test]
+fn weld_tolerance_follows_the_size_of_the_mesh() {
+    // a millimetre sized part keeps vertices a micrometre apart
+    let tiny = cuboid(Point::new(1e-3, 1e-3, 1e-3))
+        .faces
+        .iter()
+        .map(|face| [face.p1, face.p2, face.p3])
+        .collect::<Vec<_>>();
+    let mut faces = tiny.clone();
+    faces.extend(
+        tiny.iter()
+            .map(|face| face.map(|p| p + Point::new(2e-6, 0.0, 0.0))),
+    );
+    let mesh = stl::parse(ascii(&faces).as_bytes()).unwrap();
+    assert_eq!(mesh.points.len(), 16);
+}
+
+#[test]
+fn truncated_binary_is_an_error() {
+    let bytes = binary(&jittered_cube());
+    assert!(stl::parse(&bytes[..bytes.len() - 10]).is_err());
+}
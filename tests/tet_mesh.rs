@@ -0,0 +1,84 @@
+use medial_axis_3d::{
+    delaunay,
+    io::{load_tet_mesh, medit, save_tet_mesh, tetgen, vtk, TetMesh},
+    point::Point,
+};
+use std::{env, fs, io::ErrorKind, path::PathBuf};
+
+fn mesh() -> TetMesh {
+    let points = (0..12)
+        .map(|i| {
+            let i = i as f64;
+            Point::new((i * 1.3).sin(), (i * 0.7).cos(), i / 7.0)
+        })
+        .collect::<Vec<_>>();
+    TetMesh::from_tetrahedra(&delaunay(&points))
+}
+
+fn assert_same(read: &TetMesh, written: &TetMesh) {
+    assert_eq!(read.points, written.points);
+    assert_eq!(read.tetrahedra, written.tetrahedra);
+}
+
+// a fresh directory per test, so tests running in parallel don't share files
+fn scratch(name: &str) -> PathBuf {
+    let directory = env::temp_dir().join(format!("medial_axis_3d_{}", name));
+    fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+#[test]
+fn vtk_round_trips() {
+    let mesh = mesh();
+    let mut bytes = Vec::new();
+    vtk::write(&mut bytes, &mesh).unwrap();
+    assert_same(
+        &vtk::parse(&String::from_utf8(bytes).unwrap()).unwrap(),
+        &mesh,
+    );
+}
+
+#[test]
+fn medit_round_trips() {
+    let mesh = mesh();
+    let mut bytes = Vec::new();
+    medit::write(&mut bytes, &mesh).unwrap();
+    assert_same(
+        &medit::parse(&String::from_utf8(bytes).unwrap()).unwrap(),
+        &mesh,
+    );
+}
+
+#[test]
+fn tetgen_round_trips() {
+    let mesh = mesh();
+    let (mut nodes, mut elements) = (Vec::new(), Vec::new());
+    tetgen::write_nodes(&mut nodes, &mesh).unwrap();
+    tetgen::write_elements(&mut elements, &mesh).unwrap();
+    let (points, base) = tetgen::parse_nodes(&String::from_utf8(nodes).unwrap()).unwrap();
+    let tetrahedra =
+        tetgen::parse_elements(&String::from_utf8(elements).unwrap(), base, points.len()).unwrap();
+    assert_same(&TetMesh { points, tetrahedra }, &mesh);
+}
+
+#[test]
+fn every_extension_round_trips_through_files() {
+    let mesh = mesh();
+    let directory = scratch("round_trip");
+    for name in ["mesh.vtk", "mesh.mesh", "mesh.node"].iter() {
+        let path = directory.join(name);
+        save_tet_mesh(&path, &mesh).unwrap();
+        assert_same(&load_tet_mesh(&path).unwrap(), &mesh);
+    }
+    assert!(directory.join("mesh.ele").exists());
+    assert!(directory.join("mesh.face").exists());
+}
+
+#[test]
+fn unknown_extension_is_an_error() {
+    let path = scratch("unknown").join("out.txt");
+    let error = save_tet_mesh(&path, &mesh()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    assert!(!path.exists());
+    assert!(load_tet_mesh(&path).is_err());
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use common::{assert_close, cuboid};
+use medial_axis_3d::{
+    delaunay,
+    point::Point,
+    voronoi::{voronoi_cells, Bounds, VoronoiCell},
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn random_points(count: usize, size: f64, seed: u64) -> Vec<Point> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..count)
+        .map(|_| {
+            Point::new(
+                rng.gen_range(0.0..size),
+                rng.gen_range(0.0..size),
+                rng.gen_range(0.0..size),
+            )
+        })
+        .collect()
+}
+
+fn assert_symmetric(cells: &[VoronoiCell]) {
+    for (i, cell) in cells.iter().enumerate() {
+        for &j in cell.neighbours.iter() {
+            assert_ne!(i, j);
+            assert!(
+                cells[j].neighbours.contains(&i),
+                "{} lists {} as a neighbour, but not the other way around",
+                i,
+                j
+            );
+        }
+    }
+}
+
+#[test]
+fn cells_fill_their_box() {
+    let points = random_points(60, 10.0, 1);
+    let bounds = Bounds::Box {
+        min: Point::new(0.0, 0.0, 0.0),
+        max: Point::new(10.0, 10.0, 10.0),
+    };
+    let cells = voronoi_cells(&delaunay(&points), &bounds).unwrap();
+    assert_eq!(cells.len(), points.len());
+    assert_close(cells.iter().map(|cell| cell.volume).sum(), 1000.0, 1e-9);
+    assert_symmetric(&cells);
+    for cell in cells.iter() {
+        assert!(cell.volume > 0.0);
+        assert!(!cell.neighbours.is_empty());
+    }
+}
+
+#[test]
+fn cell_corners_are_closest_to_their_own_site() {
+    let points = random_points(20, 3.0, 2);
+    let bounds = Bounds::Box {
+        min: Point::new(-2.0, -2.0, -2.0),
+        max: Point::new(6.0, 3.0, 3.0),
+    };
+    let cells = voronoi_cells(&delaunay(&points), &bounds).unwrap();
+    assert_close(cells.iter().map(|cell| cell.volume).sum(), 200.0, 1e-9);
+    assert_symmetric(&cells);
+
+    for cell in cells.iter() {
+        for corner in cell.faces.iter().flatten() {
+            let own = corner.dist(&cell.site);
+            for other in cells.iter() {
+                assert!(own <= corner.dist(&other.site) + 1e-9);
+            }
+        }
+    }
+}
+
+#[test]
+fn cells_clipped_to_a_shape_fill_it() {
+    let mesh = cuboid(Point::new(2.0, 3.0, 4.0));
+    let mut points = mesh.points.clone();
+    points.extend(
+        random_points(30, 1.0, 4)
+            .iter()
+            .map(|&p| Point::new(0.2 + 1.6 * p.x, 0.2 + 2.6 * p.y, 0.2 + 3.6 * p.z)),
+    );
+    let cells = voronoi_cells(&delaunay(&points), &Bounds::Shape(&mesh.faces)).unwrap();
+    assert_eq!(cells.len(), points.len());
+    assert_close(cells.iter().map(|cell| cell.volume).sum(), 24.0, 1e-9);
+    assert_symmetric(&cells);
+}
@@ -0,0 +1,80 @@
+use medial_axis_3d::{
+    io::xyz::{self, PointCloud},
+    point::Point,
+};
+
+fn read(text: &str) -> PointCloud {
+    xyz::read(text.as_bytes()).unwrap()
+}
+
+#[test]
+fn three_columns_are_positions() {
+    let cloud = read("0 0 0\n1 2 3\n");
+    assert_eq!(
+        cloud.points,
+        vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 2.0, 3.0)]
+    );
+    assert!(cloud.normals.is_none());
+}
+
+#[test]
+fn unit_columns_after_the_position_are_normals() {
+    let cloud = read("0 0 0 0 0 1\n1 2 3 0.6 0.8 0\n");
+    assert_eq!(
+        cloud.normals,
+        Some(vec![Point::new(0.0, 0.0, 1.0), Point::new(0.6, 0.8, 0.0)])
+    );
+}
+
+#[test]
+fn colour_columns_are_not_normals() {
+    let cloud = read("0 0 0 255 128 0\n1 2 3 12 200 34\n");
+    assert_eq!(cloud.points.len(), 2);
+    assert!(cloud.normals.is_none());
+}
+
+#[test]
+fn header_names_the_normal_columns() {
+    let cloud = read("x y z r g b nx ny nz\n0 0 0 255 0 0 0 0 2\n");
+    assert_eq!(cloud.normals, Some(vec![Point::new(0.0, 0.0, 2.0)]));
+
+    let cloud = read("X,Y,Z,Intensity,A,B\n0,0,0,1,0,0\n");
+    assert!(cloud.normals.is_none());
+}
+
+#[test]
+fn normals_round_trip() {
+    let cloud = PointCloud {
+        points: vec![Point::new(1.0, 2.0, 3.0), Point::new(-1.0, 0.5, 0.0)],
+        normals: Some(vec![Point::new(0.0, 3.0, 0.0), Point::new(1.0, 0.0, 0.0)]),
+    };
+    let mut bytes = Vec::new();
+    xyz::write(&mut bytes, &cloud).unwrap();
+    let read = xyz::read(&bytes[..]).unwrap();
+    assert_eq!(read.points, cloud.points);
+    assert_eq!(read.normals, cloud.normals);
+}
+
+#[test]
+fn missing_normal_column_is_an_error() {
+    assert!(xyz::read("0 0 0 0 0 1\n1 2 3\n".as_bytes()).is_err());
+}
+
+#[test]
+fn header_names_reordered_position_columns() {
+    let cloud = read("nx ny nz x y z\n0 0 1 5 6 7\n");
+    assert_eq!(cloud.points, vec![Point::new(5.0, 6.0, 7.0)]);
+    assert_eq!(cloud.normals, Some(vec![Point::new(0.0, 0.0, 1.0)]));
+}
+
+#[test]
+fn writing_fewer_normals_than_points_is_an_error() {
+    let cloud = PointCloud {
+        points: vec![Point::new(1.0, 2.0, 3.0), Point::new(-1.0, 0.5, 0.0)],
+        normals: Some(vec![Point::new(0.0, 0.0, 1.0)]),
+    };
+    let mut bytes = Vec::new();
+    let error = xyz::write(&mut bytes, &cloud).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(bytes.is_empty());
+}